@@ -0,0 +1,34 @@
+use crate::config::Config;
+use common::animate::OnFrame;
+use common::error::SolveError;
+
+/// Parse a day's input and pass each frame of the given part's simulation to the callback, with the parameters the
+/// config gives the day.
+pub type Animator = fn(&str, u8, &Config, OnFrame) -> Result<(), SolveError>;
+
+/// The days that know how to animate themselves.
+pub fn find(day: u8) -> Option<Animator> {
+	let animator: Animator = match day {
+		14 => |input, _, _, on_frame| {
+			day_14::animate(&day_14::parse(input)?, on_frame);
+			Ok(())
+		},
+		16 => |input, _, _, on_frame| {
+			day_16::animate(&day_16::parse(input)?, on_frame);
+			Ok(())
+		},
+		17 => |input, part, config, on_frame| {
+			let grid = day_17::parse(input)?;
+
+			Ok(day_17::animate(
+				&grid,
+				part,
+				&config.day_params(17),
+				on_frame,
+			)?)
+		},
+		_ => return None,
+	};
+
+	Some(animator)
+}
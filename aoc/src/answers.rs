@@ -0,0 +1,158 @@
+use common::ParseError;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The first line of every answers file, naming the columns.
+const HEADER: &str = "# day part answer";
+
+/// The accepted answer for each day and part, as checked in to `answers.txt`.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+	entries: BTreeMap<(u8, u8), String>,
+	/// The comments above each entry, which move with it when the entries are sorted.
+	comments: BTreeMap<(u8, u8), Vec<String>>,
+	/// The comments below the last entry.
+	trailing: Vec<String>,
+}
+
+impl Answers {
+	/// Parse lines of `day part answer`, ignoring blank lines. `#` comments are kept for saving, except the header.
+	pub fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut entries = BTreeMap::new();
+		let mut comments = BTreeMap::<_, Vec<_>>::new();
+		let mut pending = Vec::new();
+
+		for (y, line) in input.lines().enumerate() {
+			if line.trim().is_empty() || line == HEADER {
+				continue;
+			}
+
+			if line.starts_with('#') {
+				pending.push(line.to_string());
+				continue;
+			}
+
+			let mut tokens = line.split_whitespace();
+			let mut next = |expected| {
+				tokens
+					.next()
+					.ok_or_else(|| ParseError::end_of_line(y, line, expected))
+			};
+
+			let day = next("a day")?;
+			let day = common::error::parse_token(y, line, day, "a day")?;
+			let part = next("a part")?;
+			let part = match part {
+				"1" => 1,
+				"2" => 2,
+				_ => return Err(ParseError::at(y, line, part, "`1` or `2`")),
+			};
+			let answer = next("an answer")?;
+
+			entries.insert((day, part), answer.to_string());
+			if !pending.is_empty() {
+				comments
+					.entry((day, part))
+					.or_default()
+					.append(&mut pending);
+			}
+		}
+
+		Ok(Self {
+			entries,
+			comments,
+			trailing: pending,
+		})
+	}
+
+	/// Load the answers file, treating a missing file as having no answers yet.
+	pub fn load(path: &Path) -> Result<Self, String> {
+		match std::fs::read_to_string(path) {
+			Ok(input) => Self::parse(&input).map_err(|e| format!("`{}`: {e}", path.display())),
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+			Err(e) => Err(format!("Could not read `{}`: {e}", path.display())),
+		}
+	}
+
+	/// Write the answers sorted by day and part under the header. Comments are kept above the entry they were above,
+	/// but blank lines are not.
+	pub fn save(&self, path: &Path) -> std::io::Result<()> {
+		std::fs::write(path, self.to_string())
+	}
+
+	pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+		self.entries.get(&(day, part)).map(|answer| answer.as_str())
+	}
+
+	pub fn insert(&mut self, day: u8, part: u8, answer: String) {
+		self.entries.insert((day, part), answer);
+	}
+}
+
+impl std::fmt::Display for Answers {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "{HEADER}")?;
+
+		for ((day, part), answer) in self.entries.iter() {
+			for comment in self.comments.get(&(*day, *part)).into_iter().flatten() {
+				writeln!(f, "{comment}")?;
+			}
+
+			writeln!(f, "{day:02} {part} {answer}")?;
+		}
+
+		for comment in self.trailing.iter() {
+			writeln!(f, "{comment}")?;
+		}
+
+		Ok(())
+	}
+}
+
+pub fn default_path() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.expect("Workspace root to exist")
+		.join("answers.txt")
+}
+
+#[cfg(test)]
+mod tests_answers {
+	use super::*;
+
+	#[test]
+	fn parse_and_display() {
+		let input = "# day part answer\n01 1 55017\n\n19 2 116738260946855\n";
+		let answers = Answers::parse(input).unwrap();
+
+		assert_eq!(answers.get(1, 1), Some("55017"));
+		assert_eq!(answers.get(19, 2), Some("116738260946855"));
+		assert_eq!(answers.get(1, 2), None);
+		assert_eq!(answers.to_string(), input.replace("\n\n", "\n"));
+	}
+
+	#[test]
+	fn comments_are_kept() {
+		let mut answers =
+			Answers::parse("# day part answer\n# Slow\n19 2 1168\n\n01 1 55017\n# Part 2 left\n")
+				.unwrap();
+		answers.insert(2, 1, "2285".to_string());
+
+		assert_eq!(
+			answers.to_string(),
+			"# day part answer\n01 1 55017\n02 1 2285\n# Slow\n19 2 1168\n# Part 2 left\n"
+		);
+	}
+
+	#[test]
+	fn parse_error_location() {
+		assert_eq!(
+			Answers::parse("01 3 55017").unwrap_err().to_string(),
+			"line 1, column 4: expected `1` or `2`, found `3`"
+		);
+		assert_eq!(
+			Answers::parse("01 1").unwrap_err().to_string(),
+			"line 1, column 5: expected an answer, found end of line"
+		);
+	}
+}
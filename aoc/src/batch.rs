@@ -0,0 +1,294 @@
+use crate::answers::Answers;
+use crate::config::Config;
+use crate::days::Day;
+use crate::run::format_duration;
+use common::input::InputSource;
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// An input file in the batch, with the answers from its sidecar file if it has one.
+pub struct Input {
+	pub path: PathBuf,
+	pub expected: Answers,
+}
+
+/// How a part's answer compares with the sidecar file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Check {
+	Pass,
+	Fail(String),
+	/// The sidecar has no answer for the part, or there is no sidecar.
+	Missing,
+	Recorded,
+}
+
+pub struct FileResult {
+	pub path: PathBuf,
+	pub part: u8,
+	pub answer: Result<String, String>,
+	pub elapsed: Duration,
+	pub check: Check,
+}
+
+impl FileResult {
+	/// Whether the part couldn't be solved or disagreed with the sidecar.
+	pub fn failed(&self) -> bool {
+		self.answer.is_err() || matches!(self.check, Check::Fail(_))
+	}
+}
+
+/// The file holding the expected answers for `input`, e.g. `alice.expected` for `alice.txt`. It uses the same
+/// `day part answer` lines as `answers.txt`.
+pub fn sidecar_path(input: &Path) -> PathBuf {
+	input.with_extension("expected")
+}
+
+/// Every input file in `dir` in name order, skipping hidden files and sidecars.
+pub fn inputs(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+	let mut paths = vec![];
+
+	for entry in std::fs::read_dir(dir)? {
+		let path = entry?.path();
+		let hidden = path
+			.file_name()
+			.is_some_and(|name| name.to_string_lossy().starts_with('.'));
+		let sidecar = path.extension().is_some_and(|ext| ext == "expected");
+
+		if path.is_file() && !hidden && !sidecar {
+			paths.push(path);
+		}
+	}
+
+	paths.sort();
+	Ok(paths)
+}
+
+/// Solve the requested parts for every input in parallel. Results come back in input order, with each file's parts
+/// together.
+pub fn run(day: &Day, inputs: &[Input], parts: &[u8], config: &Config) -> Vec<FileResult> {
+	inputs
+		.par_iter()
+		.flat_map_iter(|input| {
+			let loaded = common::input::load(day.number, &InputSource::Path(input.path.clone()));
+
+			parts
+				.iter()
+				.map(|part| {
+					let (answer, elapsed) = match &loaded {
+						Ok(text) => {
+							let now = Instant::now();
+							// A panic on one input becomes its error, so the rest of the batch still runs.
+							let answer = day.solve_caught(*part, text, config);
+
+							(answer, now.elapsed())
+						}
+						Err(e) => (Err(e.error.to_string()), Duration::ZERO),
+					};
+
+					let check = match (&answer, input.expected.get(day.number, *part)) {
+						(Ok(answer), Some(expected)) if answer == expected => Check::Pass,
+						(_, Some(expected)) => Check::Fail(expected.to_string()),
+						(_, None) => Check::Missing,
+					};
+
+					FileResult {
+						path: input.path.clone(),
+						part: *part,
+						answer,
+						elapsed,
+						check,
+					}
+				})
+				.collect::<Vec<_>>()
+		})
+		.collect()
+}
+
+/// Add the answers for parts that have no expected answer yet to each input's sidecar, marking them as recorded.
+/// Answers that disagree with a sidecar are never overwritten.
+pub fn record(day: &Day, inputs: &mut [Input], results: &mut [FileResult]) -> std::io::Result<()> {
+	for input in inputs.iter_mut() {
+		let mut changed = false;
+
+		for result in results.iter_mut().filter(|r| r.path == input.path) {
+			if let (Ok(answer), Check::Missing) = (&result.answer, &result.check) {
+				input
+					.expected
+					.insert(day.number, result.part, answer.clone());
+				result.check = Check::Recorded;
+				changed = true;
+			}
+		}
+
+		if changed {
+			input.expected.save(&sidecar_path(&input.path))?;
+		}
+	}
+
+	Ok(())
+}
+
+pub fn print_summary(dir: &Path, results: &[FileResult]) {
+	let rows = results
+		.iter()
+		.map(|r| {
+			let file = r.path.strip_prefix(dir).unwrap_or(&r.path);
+			let (answer, check) = match (&r.answer, &r.check) {
+				(Err(e), _) => ("error".to_string(), format!("FAIL, {e}")),
+				(Ok(answer), Check::Pass) => (answer.clone(), "pass".to_string()),
+				(Ok(answer), Check::Fail(expected)) => {
+					(answer.clone(), format!("FAIL, expected {expected}"))
+				}
+				(Ok(answer), Check::Missing) => (answer.clone(), "-".to_string()),
+				(Ok(answer), Check::Recorded) => (answer.clone(), "recorded".to_string()),
+			};
+
+			(
+				file.display().to_string(),
+				r.part.to_string(),
+				answer,
+				format_duration(r.elapsed),
+				check,
+			)
+		})
+		.collect::<Vec<_>>();
+
+	let width = |title: &str, column: fn(&(String, String, String, String, String)) -> usize| {
+		rows.iter().map(column).max().unwrap_or(0).max(title.len())
+	};
+	let file_width = width("File", |row| row.0.len());
+	let answer_width = width("Answer", |row| row.2.len());
+	let time_width = width("Time", |row| row.3.len());
+
+	println!(
+		" {:<file_width$} | Part | {:>answer_width$} | {:>time_width$} | Check",
+		"File", "Answer", "Time"
+	);
+	println!(
+		"-{}-+------+-{}-+-{}-+-------",
+		"-".repeat(file_width),
+		"-".repeat(answer_width),
+		"-".repeat(time_width)
+	);
+
+	for (file, part, answer, time, check) in rows {
+		println!(
+			" {file:<file_width$} | {part:>4} | {answer:>answer_width$} | {time:>time_width$} | {check}"
+		);
+	}
+
+	let count = |check: Check| {
+		results
+			.iter()
+			.filter(|r| r.answer.is_ok() && r.check == check)
+			.count()
+	};
+
+	println!();
+	println!(
+		"{} passed, {} failed, {} missing, {} recorded",
+		count(Check::Pass),
+		results.iter().filter(|r| r.failed()).count(),
+		count(Check::Missing),
+		count(Check::Recorded),
+	);
+}
+
+#[cfg(test)]
+mod tests_batch {
+	use super::*;
+	use crate::days;
+
+	fn temp_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("aoc_batch_{name}_{}", std::process::id()));
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).unwrap();
+
+		dir
+	}
+
+	#[test]
+	fn inputs_skip_sidecars_and_hidden_files() {
+		let dir = temp_dir("inputs");
+		for name in ["b.txt", "a.txt", "a.expected", ".hidden"] {
+			std::fs::write(dir.join(name), "").unwrap();
+		}
+		std::fs::create_dir(dir.join("nested")).unwrap();
+
+		assert_eq!(
+			inputs(&dir).unwrap(),
+			vec![dir.join("a.txt"), dir.join("b.txt")]
+		);
+		assert_eq!(sidecar_path(&dir.join("a.txt")), dir.join("a.expected"));
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn checks_and_records_sidecars() {
+		let dir = temp_dir("checks");
+		let day = &days::DAYS[0];
+
+		std::fs::write(dir.join("good.txt"), "1abc2\npqr3stu8vwx").unwrap();
+		std::fs::write(dir.join("good.expected"), "01 1 50").unwrap();
+		std::fs::write(dir.join("bad.txt"), "1abc2").unwrap();
+		std::fs::write(dir.join("bad.expected"), "01 1 13").unwrap();
+
+		let mut batch = inputs(&dir)
+			.unwrap()
+			.into_iter()
+			.map(|path| Input {
+				expected: Answers::load(&sidecar_path(&path)).unwrap(),
+				path,
+			})
+			.collect::<Vec<_>>();
+
+		let results = run(day, &batch, &[1], &Config::default());
+		let checks = results.iter().map(|r| r.check.clone()).collect::<Vec<_>>();
+		assert_eq!(checks, vec![Check::Fail("13".to_string()), Check::Pass]);
+		assert!(results[0].failed() && !results[1].failed());
+
+		let mut part_2 = run(day, &batch, &[2], &Config::default());
+		assert!(part_2.iter().all(|r| r.check == Check::Missing));
+
+		record(day, &mut batch, &mut part_2).unwrap();
+		assert!(part_2.iter().all(|r| r.check == Check::Recorded));
+
+		let sidecar = std::fs::read_to_string(dir.join("good.expected")).unwrap();
+		assert_eq!(sidecar, "# day part answer\n01 1 50\n01 2 50\n");
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn panics_fail_only_their_file() {
+		let dir = temp_dir("panics");
+		let day = &days::DAYS[0];
+
+		std::fs::write(dir.join("a.txt"), "1abc2").unwrap();
+		std::fs::write(dir.join("b.txt"), "1abc2\nno digits here").unwrap();
+		std::fs::write(dir.join("c.txt"), "pqr3stu8vwx").unwrap();
+
+		let batch = inputs(&dir)
+			.unwrap()
+			.into_iter()
+			.map(|path| Input {
+				path,
+				expected: Answers::default(),
+			})
+			.collect::<Vec<_>>();
+
+		let results = run(day, &batch, &[1], &Config::default());
+		let answers = results.iter().map(|r| r.answer.clone()).collect::<Vec<_>>();
+
+		assert_eq!(answers[0], Ok("12".to_string()));
+		assert!(answers[1]
+			.as_ref()
+			.is_err_and(|e| e.starts_with("the solver panicked: ")));
+		assert_eq!(answers[2], Ok("38".to_string()));
+		assert!(results[1].failed());
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+}
@@ -0,0 +1,179 @@
+use crate::days::Day;
+use crate::run::format_duration;
+use common::{ParseError, Solution};
+use std::fs::OpenOptions;
+use std::hint::black_box;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Every sample taken for each stage of a day.
+#[derive(Debug, Default)]
+pub struct Timings {
+	pub parse: Vec<Duration>,
+	pub part_1: Vec<Duration>,
+	pub part_2: Vec<Duration>,
+}
+
+impl Timings {
+	pub fn stages(&self) -> [(&'static str, &[Duration]); 3] {
+		[
+			("parse", &self.parse),
+			("part 1", &self.part_1),
+			("part 2", &self.part_2),
+		]
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Summary {
+	pub min: Duration,
+	pub median: Duration,
+	pub p95: Duration,
+}
+
+impl Summary {
+	pub fn new(samples: &[Duration]) -> Self {
+		let mut samples = samples.to_vec();
+		samples.sort();
+
+		let nearest_rank = |percentile: usize| {
+			let rank = (samples.len() * percentile).div_ceil(100).max(1);
+			samples[rank - 1]
+		};
+
+		Self {
+			min: samples[0],
+			median: nearest_rank(50),
+			p95: nearest_rank(95),
+		}
+	}
+}
+
+/// Parse and solve both parts `warmups` times without measuring, then `iterations` times timing each stage.
+pub fn measure<S: Solution>(
+	input: &str,
+	params: &S::Params,
+	warmups: usize,
+	iterations: usize,
+) -> Result<Timings, ParseError> {
+	for _ in 0..warmups {
+		let parsed = S::parse(input)?;
+		black_box(S::part_1_with(&parsed, params));
+		black_box(S::part_2_with(&parsed, params));
+	}
+
+	let mut timings = Timings::default();
+
+	for _ in 0..iterations {
+		let now = Instant::now();
+		let parsed = black_box(S::parse(black_box(input))?);
+		timings.parse.push(now.elapsed());
+
+		let now = Instant::now();
+		black_box(S::part_1_with(&parsed, params));
+		timings.part_1.push(now.elapsed());
+
+		let now = Instant::now();
+		black_box(S::part_2_with(&parsed, params));
+		timings.part_2.push(now.elapsed());
+	}
+
+	Ok(timings)
+}
+
+pub fn print_summary(day: &Day, timings: &Timings) {
+	let iterations = timings.parse.len();
+	println!("Day {:02}, {iterations} iterations", day.number);
+	println!();
+	println!(" Stage  | {:>10} | {:>10} | {:>10}", "Min", "Median", "P95");
+	println!("--------+-{0}-+-{0}-+-{0}", "-".repeat(10));
+
+	for (stage, samples) in timings.stages() {
+		let summary = Summary::new(samples);
+
+		println!(
+			" {stage:<6} | {:>10} | {:>10} | {:>10}",
+			format_duration(summary.min),
+			format_duration(summary.median),
+			format_duration(summary.p95),
+		);
+	}
+}
+
+/// Append one row per stage to a CSV file, writing the header first if the file is new.
+pub fn append_csv(path: &Path, day: &Day, timings: &Timings) -> std::io::Result<()> {
+	let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+	if file.metadata()?.len() == 0 {
+		writeln!(
+			file,
+			"timestamp,revision,day,stage,iterations,min_ms,median_ms,p95_ms"
+		)?;
+	}
+
+	let timestamp = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or(0);
+	let revision = git_revision().unwrap_or_default();
+
+	for (stage, samples) in timings.stages() {
+		let summary = Summary::new(samples);
+
+		writeln!(
+			file,
+			"{timestamp},{revision},{},{stage},{},{:.6},{:.6},{:.6}",
+			day.number,
+			samples.len(),
+			as_millis(summary.min),
+			as_millis(summary.median),
+			as_millis(summary.p95),
+		)?;
+	}
+
+	Ok(())
+}
+
+/// The short hash of the checked out commit, so results can be compared across commits.
+fn git_revision() -> Option<String> {
+	let output = std::process::Command::new("git")
+		.args(["rev-parse", "--short", "HEAD"])
+		.output()
+		.ok()?;
+
+	match output.status.success() {
+		true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+		false => None,
+	}
+}
+
+fn as_millis(duration: Duration) -> f64 {
+	duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests_bench {
+	use super::*;
+
+	#[test]
+	fn summary_uses_nearest_rank() {
+		let samples = (1..=20)
+			.rev()
+			.map(Duration::from_millis)
+			.collect::<Vec<_>>();
+		let summary = Summary::new(&samples);
+
+		assert_eq!(summary.min, Duration::from_millis(1));
+		assert_eq!(summary.median, Duration::from_millis(10));
+		assert_eq!(summary.p95, Duration::from_millis(19));
+	}
+
+	#[test]
+	fn summary_of_one_sample() {
+		let summary = Summary::new(&[Duration::from_millis(7)]);
+
+		assert_eq!(summary.min, summary.p95);
+		assert_eq!(summary.median, Duration::from_millis(7));
+	}
+}
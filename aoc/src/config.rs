@@ -0,0 +1,294 @@
+use crate::days;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Puzzle parameters from `aoc.toml` and `--set`, as a table of values for each day that changes any. `check` makes
+/// sure each table only names parameters its day has, with values of the right type that the day accepts. A parsed
+/// config has been checked; check it again after any `set`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+	days: BTreeMap<u8, Table>,
+}
+
+impl Config {
+	/// Parse a `[day_NN]` table of parameters for each day that changes them.
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let table = input
+			.parse::<Table>()
+			.map_err(|e| e.to_string().trim_end().to_string())?;
+		let mut config = Self::default();
+
+		for (key, value) in table {
+			config.merge(&key, value)?;
+		}
+
+		config.check()?;
+		Ok(config)
+	}
+
+	pub fn load(path: &Path) -> Result<Self, String> {
+		let input = std::fs::read_to_string(path)
+			.map_err(|e| format!("Could not read `{}`: {e}", path.display()))?;
+
+		Self::parse(&input).map_err(|e| format!("`{}`: {e}", path.display()))
+	}
+
+	/// Override one parameter with `day_NN.name=value`, where the value is written as it would be in TOML. The value
+	/// isn't checked until `check`, so several overrides can change parameters that depend on each other.
+	pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+		let (day, name, value) = assignment
+			.split_once('=')
+			.and_then(|(key, value)| {
+				let (day, name) = key.trim().split_once('.')?;
+				Some((day, name, value.trim()))
+			})
+			.ok_or_else(|| format!("expected `day_NN.name=value`, found `{assignment}`"))?;
+
+		let value = format!("value = {value}")
+			.parse::<Table>()
+			.ok()
+			.and_then(|mut table| table.remove("value"))
+			.ok_or_else(|| format!("`{value}` is not a TOML value, e.g. `10` or `\"text\"`"))?;
+
+		self.merge(
+			day,
+			Value::Table(Table::from_iter([(name.to_string(), value)])),
+		)
+	}
+
+	/// Check every day's table against its parameters.
+	pub fn check(&self) -> Result<(), String> {
+		for (number, section) in &self.days {
+			let day = days::find(*number).expect("Config to only hold days with solutions");
+
+			(day.check_params)(section).map_err(|e| format!("`day_{number:02}`: {e}"))?;
+		}
+
+		Ok(())
+	}
+
+	/// The parameters a day was given, or `None` when it solves with its defaults.
+	pub fn section(&self, day: u8) -> Option<&Table> {
+		self.days.get(&day)
+	}
+
+	/// A day's parameters, with its defaults for any the config doesn't give.
+	pub fn day_params<P: Default + DeserializeOwned>(&self, day: u8) -> P {
+		params(self.section(day)).expect("Parameters to be checked by the config")
+	}
+
+	/// Add the values in a day's table over any it already has.
+	fn merge(&mut self, key: &str, value: Value) -> Result<(), String> {
+		let day = key
+			.strip_prefix("day_")
+			.and_then(|number| number.parse().ok())
+			.and_then(days::find)
+			.ok_or_else(|| {
+				format!("`{key}` is not a day with a solution, expected e.g. `day_11`")
+			})?;
+
+		let Value::Table(values) = value else {
+			return Err(format!("`{key}` should be a table of parameters"));
+		};
+
+		self.days.entry(day.number).or_default().extend(values);
+
+		Ok(())
+	}
+}
+
+/// A day's parameters from its table, with the defaults for any it leaves out.
+pub fn params<P: Default + DeserializeOwned>(
+	section: Option<&Table>,
+) -> Result<P, toml::de::Error> {
+	match section {
+		Some(section) => Value::Table(section.clone()).try_into(),
+		None => Ok(P::default()),
+	}
+}
+
+/// `aoc.toml` in the workspace root.
+pub fn default_path() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.expect("Workspace root to exist")
+		.join("aoc.toml")
+}
+
+#[cfg(test)]
+mod tests_config {
+	use super::*;
+
+	#[test]
+	fn sections_become_parameters() {
+		let config =
+			Config::parse("[day_11]\nexpansion = 10\n\n[day_17]\ncrucible_max = 5").unwrap();
+
+		assert_eq!(
+			params::<day_11::Params>(config.section(11)).unwrap(),
+			day_11::Params { expansion: 10 }
+		);
+		assert_eq!(
+			params::<day_17::Params>(config.section(17)).unwrap(),
+			day_17::Params {
+				crucible_max: 5,
+				..day_17::Params::default()
+			}
+		);
+		assert_eq!(config.section(2), None);
+		assert_eq!(
+			params::<day_02::Params>(None).unwrap(),
+			day_02::Params::default()
+		);
+	}
+
+	#[test]
+	fn set_overrides_file() {
+		let mut config = Config::parse("[day_02]\nred = 20\ngreen = 20").unwrap();
+		config.set("day_02.green=30").unwrap();
+		config.set(" day_14.cycles = 3 ").unwrap();
+
+		assert_eq!(
+			params::<day_02::Params>(config.section(2)).unwrap(),
+			day_02::Params {
+				red: 20,
+				green: 30,
+				blue: 14,
+			}
+		);
+		assert_eq!(
+			params::<day_14::Params>(config.section(14)).unwrap(),
+			day_14::Params { cycles: 3 }
+		);
+	}
+
+	#[test]
+	fn bad_parameters_are_refused() {
+		let error = |input| Config::parse(input).unwrap_err();
+
+		assert_eq!(
+			error("[day_11]\nexpanse = 10"),
+			"`day_11`: unknown field `expanse`, expected `expansion`"
+		);
+		assert_eq!(
+			error("[day_11]\nexpansion = \"big\""),
+			"`day_11`: invalid type: string \"big\", expected u64"
+		);
+		assert_eq!(
+			error("[day_03]\nsize = 1"),
+			"`day_03`: this day has no parameters"
+		);
+		assert_eq!(
+			error("[day_25]"),
+			"`day_25` is not a day with a solution, expected e.g. `day_11`"
+		);
+		assert_eq!(
+			error("day_11 = 10"),
+			"`day_11` should be a table of parameters"
+		);
+
+		let mut config = Config::default();
+		assert_eq!(
+			config.set("day_11.expansion"),
+			Err("expected `day_NN.name=value`, found `day_11.expansion`".to_string())
+		);
+		assert_eq!(
+			config.set("day_11.expansion=ten"),
+			Err("`ten` is not a TOML value, e.g. `10` or `\"text\"`".to_string())
+		);
+		assert_eq!(config, Config::default());
+	}
+
+	#[test]
+	fn values_days_reject_are_refused() {
+		let error = |input| Config::parse(input).unwrap_err();
+
+		assert_eq!(
+			error("[day_17]\ncrucible_max = 0"),
+			"`day_17`: `crucible_max` must be at least 1"
+		);
+		assert_eq!(
+			error("[day_17]\ncrucible_min = 5\ncrucible_max = 2"),
+			"`day_17`: `crucible_min` (5) must not be more than `crucible_max` (2)"
+		);
+		assert_eq!(
+			error("[day_17]\nultra_crucible_min = 11"),
+			"`day_17`: `ultra_crucible_min` (11) must not be more than `ultra_crucible_max` (10)"
+		);
+		assert_eq!(
+			error("[day_19]\nmin_rating = 10\nmax_rating = 9"),
+			"`day_19`: `min_rating` (10) must not be more than `max_rating` (9)"
+		);
+		assert_eq!(
+			error("[day_19]\nmax_rating = 100000"),
+			"`day_19`: `min_rating` to `max_rating` can span at most 65535 ratings"
+		);
+		assert_eq!(
+			error("[day_11]\nexpansion = 0"),
+			"`day_11`: `expansion` must be at least 1"
+		);
+		assert_eq!(
+			error("[day_12]\nunfold = 0"),
+			"`day_12`: `unfold` must be at least 1"
+		);
+		assert_eq!(
+			error("[day_14]\ncycles = 0"),
+			"`day_14`: `cycles` must be at least 1"
+		);
+	}
+
+	#[test]
+	fn overrides_are_checked_together() {
+		let mut config = Config::default();
+		config.set("day_17.crucible_min=5").unwrap();
+		config.set("day_17.crucible_max=10").unwrap();
+		assert_eq!(config.check(), Ok(()));
+
+		config.set("day_17.crucible_max=2").unwrap();
+		assert_eq!(
+			config.check(),
+			Err(
+				"`day_17`: `crucible_min` (5) must not be more than `crucible_max` (2)".to_string()
+			)
+		);
+
+		config.set("day_11.expanse=10").unwrap();
+		config.set("day_17.crucible_max=10").unwrap();
+		assert_eq!(
+			config.check(),
+			Err("`day_11`: unknown field `expanse`, expected `expansion`".to_string())
+		);
+	}
+
+	#[test]
+	fn workspace_config_is_the_defaults() {
+		let config = Config::load(&default_path()).unwrap();
+
+		assert_eq!(
+			params::<day_02::Params>(config.section(2)).unwrap(),
+			day_02::Params::default()
+		);
+		assert_eq!(
+			params::<day_11::Params>(config.section(11)).unwrap(),
+			day_11::Params::default()
+		);
+		assert_eq!(
+			params::<day_12::Params>(config.section(12)).unwrap(),
+			day_12::Params::default()
+		);
+		assert_eq!(
+			params::<day_14::Params>(config.section(14)).unwrap(),
+			day_14::Params::default()
+		);
+		assert_eq!(
+			params::<day_17::Params>(config.section(17)).unwrap(),
+			day_17::Params::default()
+		);
+		assert_eq!(
+			params::<day_19::Params>(config.section(19)).unwrap(),
+			day_19::Params::default()
+		);
+	}
+}
@@ -0,0 +1,135 @@
+use crate::bench::{self, Timings};
+use crate::config::{self, Config};
+use common::error::SolveError;
+use common::solution::{Answer, Params};
+use common::{ParseError, Solution};
+use serde::de::DeserializeOwned;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use toml::Table;
+
+/// Entry points for a single day's solutions. Those taking a table of parameters solve with the day's defaults when
+/// it is `None`.
+pub struct Day {
+	pub number: u8,
+	pub solve: fn(u8, &str, Option<&Table>) -> Result<String, SolveError>,
+	pub bench: fn(&str, Option<&Table>, usize, usize) -> Result<Timings, ParseError>,
+	pub answer_type: fn(u8) -> &'static str,
+	pub check_params: fn(&Table) -> Result<(), String>,
+}
+
+impl Day {
+	/// Solve a part as the puzzle sets it, ignoring any parameters in the config.
+	pub fn solve(&self, part: u8, input: &str) -> Result<String, SolveError> {
+		(self.solve)(part, input, None)
+	}
+
+	/// Solve a part with the parameters the config gives this day.
+	pub fn solve_with(&self, part: u8, input: &str, config: &Config) -> Result<String, SolveError> {
+		(self.solve)(part, input, config.section(self.number))
+	}
+
+	/// Solve a part with the config's parameters, as [`Day::solve_with`] does, giving any error as text. A panic in the
+	/// solver is caught and becomes the part's error.
+	pub fn solve_caught(&self, part: u8, input: &str, config: &Config) -> Result<String, String> {
+		match std::panic::catch_unwind(AssertUnwindSafe(|| self.solve_with(part, input, config))) {
+			Ok(answer) => answer.map_err(|e| e.to_string()),
+			Err(panic) => Err(format!("the solver panicked: {}", panic_message(&*panic))),
+		}
+	}
+
+	/// The Rust type of the given part's answer.
+	pub fn answer_type(&self, part: u8) -> &'static str {
+		(self.answer_type)(part)
+	}
+
+	pub fn bench(
+		&self,
+		input: &str,
+		config: &Config,
+		warmups: usize,
+		iterations: usize,
+	) -> Result<Timings, ParseError> {
+		(self.bench)(input, config.section(self.number), warmups, iterations)
+	}
+}
+
+fn solve<S: Solution>(part: u8, input: &str, section: Option<&Table>) -> Result<String, SolveError>
+where
+	S::Params: DeserializeOwned,
+{
+	let params =
+		config::params::<S::Params>(section).expect("Parameters to be checked by the config");
+
+	common::solution::solve::<S>(part, input, &params)
+}
+
+fn bench<S: Solution>(
+	input: &str,
+	section: Option<&Table>,
+	warmups: usize,
+	iterations: usize,
+) -> Result<Timings, ParseError>
+where
+	S::Params: DeserializeOwned,
+{
+	let params =
+		config::params::<S::Params>(section).expect("Parameters to be checked by the config");
+
+	bench::measure::<S>(input, &params, warmups, iterations)
+}
+
+/// Whether a table of parameters is valid for the day: it must have parameters, and the table must name only those,
+/// with values of the right type that pass the day's own checks.
+fn check_params<S: Solution>(section: &Table) -> Result<(), String>
+where
+	S::Params: DeserializeOwned,
+{
+	match S::Params::is_empty() {
+		true => Err("this day has no parameters".to_string()),
+		false => config::params::<S::Params>(Some(section))
+			.map_err(|e| e.message().trim_end().to_string())
+			.and_then(|params| params.validate()),
+	}
+}
+
+/// The message a panic was raised with, when it has one.
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+	match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+		(Some(message), _) => message,
+		(_, Some(message)) => message,
+		_ => "no message",
+	}
+}
+
+fn answer_type<S: Solution>(part: u8) -> &'static str {
+	match part {
+		1 => S::Part1::type_name(),
+		2 => S::Part2::type_name(),
+		_ => unreachable!("Only parts 1 and 2 exist"),
+	}
+}
+
+macro_rules! day {
+	($number:literal, $solution:ty) => {
+		Day {
+			number: $number,
+			solve: solve::<$solution>,
+			bench: bench::<$solution>,
+			answer_type: answer_type::<$solution>,
+			check_params: check_params::<$solution>,
+		}
+	};
+}
+
+macro_rules! registry {
+	($($number:literal => $krate:ident::$solution:ident $(checked by $compare:ident)?),* $(,)?) => {
+		pub static DAYS: &[Day] = &[$(day!($number, $krate::$solution)),*];
+	};
+}
+
+common::days!(registry);
+
+pub fn find(number: u8) -> Option<&'static Day> {
+	DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,37 @@
+use common::differential::{check, Mismatch};
+use std::ops::Range;
+
+/// Solve inputs of the given size, generated from each seed, with a day's reference and optimised solvers.
+pub type Checker = fn(usize, Range<u64>) -> Result<(), Mismatch>;
+
+macro_rules! checkers {
+	($($number:literal => $krate:ident::$solution:ident $(checked by $compare:ident)?),* $(,)?) => {
+		/// The checker for every day that keeps a slower or simpler reference solver around.
+		pub fn find(day: u8) -> Option<Checker> {
+			let checker: Checker = match day {
+				$($($number => |size, seeds| check($krate::generate, size, seeds, $krate::$compare),)?)*
+				_ => return None,
+			};
+
+			Some(checker)
+		}
+	};
+}
+
+common::days!(checkers);
+
+#[cfg(test)]
+mod tests_differential {
+	use super::*;
+
+	#[test]
+	fn checked_days_agree() {
+		for day in [5, 6, 10, 18, 19] {
+			let check = find(day).expect("Day to have a checker");
+
+			assert_eq!(check(8, 0..10), Ok(()), "Day {day} solvers to agree");
+		}
+
+		assert!(find(1).is_none());
+	}
+}
@@ -0,0 +1,56 @@
+use common::rng::Rng;
+
+/// Produce a random input of roughly the given size. What the size counts is up to each day.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+macro_rules! generators {
+	($($number:literal => $krate:ident::$solution:ident $(checked by $compare:ident)?),* $(,)?) => {
+		/// The generator for every solved day.
+		pub fn find(day: u8) -> Option<Generator> {
+			let generator: Generator = match day {
+				$($number => $krate::generate,)*
+				_ => return None,
+			};
+
+			Some(generator)
+		}
+	};
+}
+
+common::days!(generators);
+
+#[cfg(test)]
+mod tests_generate {
+	use super::*;
+	use crate::days;
+
+	#[test]
+	fn generated_inputs_solve() {
+		for day in days::DAYS.iter() {
+			let generate = find(day.number).expect("Every day to have a generator");
+			let input = generate(&mut Rng::new(1), 8);
+
+			for part in [1, 2] {
+				assert!(
+					day.solve(part, &input).is_ok(),
+					"Day {} part {part} to solve its generated input",
+					day.number
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn same_seed_same_input() {
+		let generate = find(19).unwrap();
+
+		assert_eq!(
+			generate(&mut Rng::new(3), 10),
+			generate(&mut Rng::new(3), 10)
+		);
+		assert_ne!(
+			generate(&mut Rng::new(3), 10),
+			generate(&mut Rng::new(4), 10)
+		);
+	}
+}
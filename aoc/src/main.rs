@@ -0,0 +1,565 @@
+use clap::{Args, Parser, Subcommand};
+use common::animate::Animation;
+use common::input::InputSource;
+use common::rng::Rng;
+use config::Config;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+mod animate;
+mod answers;
+mod batch;
+mod bench;
+mod config;
+mod days;
+mod differential;
+mod generate;
+mod new_day;
+mod render;
+mod run;
+mod serve;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+struct Cli {
+	#[command(subcommand)]
+	command: Command,
+
+	/// Print diagnostics to stderr: `-v` for info, `-vv` for debug and `-vvv` for trace. `RUST_LOG` overrides this.
+	#[arg(long, short, global = true, action = clap::ArgAction::Count)]
+	verbose: u8,
+
+	/// Puzzle parameters to solve with. Defaults to `aoc.toml` in the workspace root.
+	#[arg(long, global = true)]
+	config: Option<PathBuf>,
+
+	/// Override a puzzle parameter from the config, e.g. `--set day_11.expansion=10`. Can be repeated.
+	#[arg(long = "set", global = true, value_name = "DAY_NN.NAME=VALUE")]
+	overrides: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Solve a single day, or every day with `--all`.
+	Run(RunArgs),
+	/// Solve a day for every input file in a directory, in parallel.
+	Batch(BatchArgs),
+	/// Time the parse stage and both parts of a day over many iterations.
+	Bench(BenchArgs),
+	/// Check every day's answers against the recorded answers file, solving each puzzle as it is set.
+	Verify(VerifyArgs),
+	/// Create a new day crate from the `day_00` template.
+	NewDay(NewDayArgs),
+	/// Draw a day's grid or polygon to a PPM or SVG image.
+	Render(RenderArgs),
+	/// Replay a day's simulation in the terminal.
+	Animate(AnimateArgs),
+	/// Write a random input for a day, for fuzzing and scaling tests.
+	Gen(GenArgs),
+	/// Compare a day's optimised solver against its brute-force reference on generated inputs.
+	Diff(DiffArgs),
+	/// Answer `POST /day/{n}/part/{p}` requests over HTTP, solving the body as the input.
+	Serve(ServeArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+	/// Day to solve.
+	#[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: Option<u8>,
+
+	/// Only solve the given part.
+	#[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+	part: Option<u8>,
+
+	/// Solve every day.
+	#[arg(long, conflicts_with = "day")]
+	all: bool,
+
+	/// Read the input from this file, or from stdin when `-`. Defaults to `day_XX/src/part_1_input.txt`.
+	#[arg(long, short, conflicts_with = "all")]
+	input: Option<String>,
+
+	/// How to report the results.
+	#[arg(long, value_enum, default_value_t = run::Format::Text)]
+	format: run::Format,
+}
+
+#[derive(Args)]
+struct BatchArgs {
+	/// Day to solve.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+
+	/// Directory of inputs. Each input may have a sidecar of expected answers, e.g. `alice.expected` for
+	/// `alice.txt`, in the same format as `answers.txt`.
+	dir: PathBuf,
+
+	/// Only solve the given part.
+	#[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+	part: Option<u8>,
+
+	/// Write answers to the sidecars for parts that don't have one yet.
+	#[arg(long)]
+	record: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+	/// Day to benchmark.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+
+	/// Untimed runs before measuring.
+	#[arg(long, default_value_t = 3)]
+	warmup: usize,
+
+	/// Timed runs to summarise.
+	#[arg(long, short = 'n', default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+	iterations: u32,
+
+	/// Read the input from this file, or from stdin when `-`. Defaults to `day_XX/src/part_1_input.txt`.
+	#[arg(long, short)]
+	input: Option<String>,
+
+	/// Append the results to this CSV file.
+	#[arg(long)]
+	csv: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+	/// Only verify the given day.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: Option<u8>,
+
+	/// Record answers for parts that don't have one yet.
+	#[arg(long)]
+	record: bool,
+
+	/// Answers file to check against. Defaults to `answers.txt` in the workspace root.
+	#[arg(long)]
+	answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+	/// Day to create.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+	/// Day to draw. Days 10, 16, 17 and 18 can be drawn.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+
+	/// Part whose result to draw.
+	#[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+	part: u8,
+
+	/// Image to write, as `.ppm` or `.svg`.
+	#[arg(long, short)]
+	output: PathBuf,
+
+	/// Read the input from this file, or from stdin when `-`. Defaults to `day_XX/src/part_1_input.txt`.
+	#[arg(long, short)]
+	input: Option<String>,
+
+	/// Pixels per grid cell.
+	#[arg(long, default_value_t = 4)]
+	scale: usize,
+
+	/// Length in pixels of the longest side of a polygon.
+	#[arg(long, default_value_t = 800)]
+	size: usize,
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+	/// Day to animate. Days 14, 16 and 17 can be animated.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+
+	/// Part whose simulation to show.
+	#[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+	part: u8,
+
+	/// Read the input from this file. Defaults to `day_XX/src/part_1_input.txt`.
+	#[arg(long, short)]
+	input: Option<String>,
+
+	/// Frames drawn per second.
+	#[arg(long, default_value_t = 10.0)]
+	fps: f64,
+
+	/// Wait for a key press before drawing each frame.
+	#[arg(long)]
+	step: bool,
+
+	/// Only draw every Nth frame, for simulations with many steps.
+	#[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+	every: u32,
+}
+
+#[derive(Args)]
+struct GenArgs {
+	/// Day to generate an input for.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+
+	/// How big to make the input, e.g. the number of lines or the width of the grid.
+	#[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+	size: u32,
+
+	/// Seed for the generator. A random seed is picked and printed to stderr when not given.
+	#[arg(long)]
+	seed: Option<u64>,
+
+	/// Write the input to this file instead of stdout.
+	#[arg(long, short)]
+	output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+	/// Day to check. Days 5, 6, 10, 18 and 19 have a reference solver.
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: u8,
+
+	/// How big to make each input, as for `gen`.
+	#[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+	size: u32,
+
+	/// First seed to generate an input from.
+	#[arg(long, default_value_t = 0)]
+	seed: u64,
+
+	/// How many inputs to check.
+	#[arg(long, short = 'n', default_value_t = 100)]
+	runs: u64,
+}
+
+#[derive(Args)]
+struct ServeArgs {
+	/// Address to listen on. Use `0.0.0.0` to accept requests from other machines.
+	#[arg(long, default_value = "127.0.0.1")]
+	host: String,
+
+	/// Port to listen on.
+	#[arg(long, short, default_value_t = 8080)]
+	port: u16,
+
+	/// How many requests to solve at once.
+	#[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+	workers: u32,
+}
+
+fn main() {
+	let cli = Cli::parse();
+	common::logging::init(cli.verbose);
+
+	let config = || load_config(cli.config.as_deref(), &cli.overrides);
+
+	match cli.command {
+		Command::Run(args) => run(args, &config()),
+		Command::Batch(args) => batch(args, &config()),
+		Command::Bench(args) => bench(args, &config()),
+		Command::Verify(args) => verify(args),
+		Command::NewDay(args) => new_day(args),
+		Command::Render(args) => render(args, &config()),
+		Command::Animate(args) => animate(args, &config()),
+		Command::Gen(args) => generate(args),
+		Command::Diff(args) => diff(args),
+		Command::Serve(args) => serve(args, &config()),
+	}
+}
+
+fn run(args: RunArgs, config: &Config) {
+	let days = match args.day {
+		Some(number) => vec![find_day(number)],
+		None => days::DAYS.iter().collect(),
+	};
+
+	let parts = match args.part {
+		Some(part) => vec![part],
+		None => vec![1, 2],
+	};
+
+	let source = InputSource::from_arg(args.input.as_deref());
+
+	let days = days
+		.into_iter()
+		.map(|day| (day, common::input::load(day.number, &source)))
+		.collect::<Vec<_>>();
+
+	let results = run::run(&days, &parts, args.format, config);
+
+	match args.format {
+		run::Format::Text => run::print_summary(&results),
+		run::Format::Json => run::print_json(&results),
+	}
+
+	if results.iter().any(|r| r.answer.is_err()) {
+		std::process::exit(1);
+	}
+}
+
+fn batch(args: BatchArgs, config: &Config) {
+	let day = find_day(args.day);
+
+	let parts = match args.part {
+		Some(part) => vec![part],
+		None => vec![1, 2],
+	};
+
+	let paths = match batch::inputs(&args.dir) {
+		Ok(paths) => paths,
+		Err(e) => {
+			eprintln!("Could not read `{}`: {e}", args.dir.display());
+			std::process::exit(1);
+		}
+	};
+
+	let mut inputs = paths
+		.into_iter()
+		.map(|path| batch::Input {
+			expected: common::error::or_exit(answers::Answers::load(&batch::sidecar_path(&path))),
+			path,
+		})
+		.collect::<Vec<_>>();
+
+	let mut results = batch::run(day, &inputs, &parts, config);
+
+	if args.record {
+		if let Err(e) = batch::record(day, &mut inputs, &mut results) {
+			eprintln!("Could not write expected answers: {e}");
+			std::process::exit(1);
+		}
+	}
+
+	batch::print_summary(&args.dir, &results);
+
+	if results.iter().any(batch::FileResult::failed) {
+		std::process::exit(1);
+	}
+}
+
+fn bench(args: BenchArgs, config: &Config) {
+	let day = find_day(args.day);
+	let source = InputSource::from_arg(args.input.as_deref());
+	let input = common::error::or_exit(common::input::load(day.number, &source));
+
+	let timings = match day.bench(&input, config, args.warmup, args.iterations as usize) {
+		Ok(timings) => timings,
+		Err(e) => {
+			eprintln!("Day {:02}: could not parse input: {e}", day.number);
+			std::process::exit(1);
+		}
+	};
+
+	bench::print_summary(day, &timings);
+
+	if let Some(path) = args.csv {
+		if let Err(e) = bench::append_csv(&path, day, &timings) {
+			eprintln!(
+				"Could not write benchmark results to `{}`: {e}",
+				path.display()
+			);
+			std::process::exit(1);
+		}
+	}
+}
+
+fn verify(args: VerifyArgs) {
+	let days = match args.day {
+		Some(number) => vec![find_day(number)],
+		None => days::DAYS.iter().collect(),
+	};
+
+	let path = args.answers.unwrap_or_else(answers::default_path);
+	let mut answers = common::error::or_exit(answers::Answers::load(&path));
+	let mut failed = false;
+
+	let days = days
+		.into_iter()
+		.filter_map(
+			|day| match common::input::load(day.number, &InputSource::Default) {
+				Ok(input) => Some((day, input)),
+				Err(e) => {
+					eprintln!("{e}");
+					failed = true;
+					None
+				}
+			},
+		)
+		.collect::<Vec<_>>();
+
+	let report = verify::verify(&days, &mut answers, args.record);
+	verify::print_summary(&report);
+
+	if report.recorded > 0 {
+		if let Err(e) = answers.save(&path) {
+			eprintln!("Could not write answers to `{}`: {e}", path.display());
+			failed = true;
+		}
+	}
+
+	std::process::exit(match failed {
+		true => 1,
+		false => report.exit_code(),
+	});
+}
+
+fn new_day(args: NewDayArgs) {
+	let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.expect("Workspace root to exist");
+
+	let dir = common::error::or_exit(new_day::create(root, args.day));
+
+	println!("Created `{}`", dir.display());
+}
+
+fn render(args: RenderArgs, config: &Config) {
+	let Some(renderer) = render::find(args.day) else {
+		eprintln!("Day {} can't be drawn", args.day);
+		std::process::exit(1);
+	};
+
+	let source = InputSource::from_arg(args.input.as_deref());
+	let input = common::error::or_exit(common::input::load(args.day, &source));
+
+	let image = match renderer(&input, args.part, config) {
+		Ok(image) => image,
+		Err(e) => {
+			eprintln!("Day {:02}: {e}", args.day);
+			std::process::exit(1);
+		}
+	};
+
+	common::error::or_exit(render::write(&image, &args.output, args.scale, args.size));
+	println!("Wrote `{}`", args.output.display());
+}
+
+fn animate(args: AnimateArgs, config: &Config) {
+	let Some(animator) = animate::find(args.day) else {
+		eprintln!("Day {} can't be animated", args.day);
+		std::process::exit(1);
+	};
+
+	if args.step && args.input.as_deref() == Some("-") {
+		eprintln!(
+			"`--step` reads keypresses from stdin, so the input can't be read from stdin too"
+		);
+		std::process::exit(1);
+	}
+
+	let source = InputSource::from_arg(args.input.as_deref());
+	let input = common::error::or_exit(common::input::load(args.day, &source));
+
+	let mut animation = Animation::new(args.fps, args.step, args.every as usize);
+	let result = animator(&input, args.part, config, &mut |frame, last| {
+		animation.frame(frame, last)
+	});
+	animation.finish();
+
+	if let Err(e) = result {
+		eprintln!("Day {:02}: {e}", args.day);
+		std::process::exit(1);
+	}
+}
+
+fn generate(args: GenArgs) {
+	let Some(generator) = generate::find(args.day) else {
+		eprintln!("No generator exists for day {}", args.day);
+		std::process::exit(1);
+	};
+
+	let seed = args.seed.unwrap_or_else(|| {
+		let seed = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|d| d.as_nanos() as u64)
+			.unwrap_or(0);
+		eprintln!("Seed {seed}");
+		seed
+	});
+
+	let input = generator(&mut Rng::new(seed), args.size as usize);
+
+	match args.output {
+		Some(path) => {
+			if let Err(e) = std::fs::write(&path, input + "\n") {
+				eprintln!("Could not write `{}`: {e}", path.display());
+				std::process::exit(1);
+			}
+		}
+		None => println!("{input}"),
+	}
+}
+
+fn diff(args: DiffArgs) {
+	let Some(checker) = differential::find(args.day) else {
+		eprintln!(
+			"Day {} has no reference solver to compare against",
+			args.day
+		);
+		std::process::exit(1);
+	};
+
+	match checker(args.size as usize, args.seed..args.seed + args.runs) {
+		Ok(()) => println!("Day {:02}: {} inputs agree", args.day, args.runs),
+		Err(mismatch) => {
+			eprintln!("Day {:02}: {mismatch}", args.day);
+			std::process::exit(1);
+		}
+	}
+}
+
+fn serve(args: ServeArgs, config: &Config) {
+	let address = format!("{}:{}", args.host, args.port);
+
+	let server = match tiny_http::Server::http(&address) {
+		Ok(server) => server,
+		Err(e) => {
+			eprintln!("Could not listen on `{address}`: {e}");
+			std::process::exit(1);
+		}
+	};
+
+	println!("Listening on http://{}", server.server_addr());
+	serve::serve(&server, args.workers as usize, config);
+}
+
+/// The config file, or the workspace's `aoc.toml` if it has one, with the overrides applied in order.
+fn load_config(path: Option<&Path>, overrides: &[String]) -> Config {
+	let default_path = config::default_path();
+
+	let mut config = match path {
+		Some(path) => common::error::or_exit(Config::load(path)),
+		None if default_path.exists() => common::error::or_exit(Config::load(&default_path)),
+		None => Config::default(),
+	};
+
+	for assignment in overrides {
+		if let Err(e) = config.set(assignment) {
+			eprintln!("`--set {assignment}`: {e}");
+			std::process::exit(1);
+		}
+	}
+
+	common::error::or_exit(config.check());
+	config
+}
+
+fn find_day(number: u8) -> &'static days::Day {
+	match days::find(number) {
+		Some(day) => day,
+		None => {
+			eprintln!("No solution exists for day {number}");
+			std::process::exit(1);
+		}
+	}
+}
@@ -0,0 +1,277 @@
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = "day_00";
+
+/// The file holding the list of days that the runner, the FFI library and the tests expand their tables from.
+const REGISTRY: &str = "common/src/days.rs";
+
+/// The crates that depend on every day.
+const DEPENDENTS: [&str; 2] = ["aoc/Cargo.toml", "ffi/Cargo.toml"];
+
+/// Copy the `day_00` template under `root` to a new `day_NN` crate, add it to the workspace members and the day
+/// registry, and make the runner and the FFI library depend on it. The new day gets empty input and example files, and
+/// refuses to replace anything that already exists.
+pub fn create(root: &Path, day: u8) -> Result<PathBuf, String> {
+	let name = format!("day_{day:02}");
+	let dir = root.join(&name);
+
+	if day == 0 {
+		return Err(format!(
+			"`{TEMPLATE}` is the template and can't be generated"
+		));
+	}
+
+	if dir.exists() {
+		return Err(format!("`{}` already exists", dir.display()));
+	}
+
+	let manifest_path = root.join("Cargo.toml");
+	let manifest = read(&manifest_path)?;
+	let manifest = add_member(&manifest, &name)
+		.ok_or_else(|| format!("`{name}` is already a workspace member"))?;
+
+	let registry_path = root.join(REGISTRY);
+	let registry = read(&registry_path)?;
+	let registry =
+		add_day(&registry, day).ok_or_else(|| format!("Day {day} is already in `{REGISTRY}`"))?;
+
+	let mut edits = vec![(manifest_path, manifest), (registry_path, registry)];
+	for dependent in DEPENDENTS {
+		let path = root.join(dependent);
+		let dependencies = add_dependency(&read(&path)?, &name)
+			.ok_or_else(|| format!("`{dependent}` already depends on `{name}`"))?;
+
+		edits.push((path, dependencies));
+	}
+
+	let template = root.join(TEMPLATE);
+	let rename = |contents: String| {
+		contents
+			.replace(TEMPLATE, &name)
+			.replace("Day00", &format!("Day{day:02}"))
+			.replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+	};
+
+	let files = [
+		("Cargo.toml", rename(read(&template.join("Cargo.toml"))?)),
+		("src/lib.rs", rename(read(&template.join("src/lib.rs"))?)),
+		("src/main.rs", rename(read(&template.join("src/main.rs"))?)),
+		("src/part_1_input.txt", String::new()),
+		("src/example.txt", String::new()),
+	];
+
+	std::fs::create_dir_all(dir.join("src"))
+		.map_err(|e| format!("Could not create `{}`: {e}", dir.display()))?;
+
+	for (file, contents) in files {
+		write(&dir.join(file), &contents)?;
+	}
+
+	for (path, contents) in edits {
+		write(&path, &contents)?;
+	}
+
+	Ok(dir)
+}
+
+/// Insert `name` into the workspace `members` list, keeping it sorted. `None` if it's already there.
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+	insert_sorted(
+		manifest,
+		|line| line.trim_start().starts_with("members = ["),
+		|line| line.trim() == "]",
+		&format!("    \"{name}\","),
+		|line| Some(line.trim().trim_end_matches(',').to_string()),
+	)
+}
+
+/// Insert `day` into the list the registry macro passes on, keeping it in day order. `None` if it's already there.
+fn add_day(registry: &str, day: u8) -> Option<String> {
+	insert_sorted(
+		registry,
+		|line| line.trim() == "$table! {",
+		|line| line.trim() == "}",
+		&format!("\t\t\t{day} => day_{day:02}::Day{day:02},"),
+		|line| line.trim().split_once(" =>")?.0.parse::<u8>().ok(),
+	)
+}
+
+/// Insert a path dependency on the day crate `name` into a manifest's `[dependencies]`, keeping them sorted. `None` if
+/// it's already there.
+fn add_dependency(manifest: &str, name: &str) -> Option<String> {
+	insert_sorted(
+		manifest,
+		|line| line.trim() == "[dependencies]",
+		|line| line.trim().is_empty() || line.starts_with('['),
+		&format!("{name} = {{ path = \"../{name}\" }}"),
+		|line| Some(line.split_once('=')?.0.trim().to_string()),
+	)
+}
+
+/// Insert `entry` into the block of lines after the first line `opens` matches and before the next line `closes`
+/// matches, or the end of `text`. The entry goes before the first line with a greater `key`, so a sorted block stays
+/// sorted. `None` if a line with the same key is already there.
+fn insert_sorted<K: Ord>(
+	text: &str,
+	opens: impl Fn(&str) -> bool,
+	closes: impl Fn(&str) -> bool,
+	entry: &str,
+	key: impl Fn(&str) -> Option<K>,
+) -> Option<String> {
+	let mut lines = text.lines().collect::<Vec<_>>();
+	let entry_key = key(entry).expect("Inserted line to have a key");
+
+	let start = lines
+		.iter()
+		.position(|line| opens(line))
+		.expect("Block to be opened");
+	let end = lines[(start + 1)..]
+		.iter()
+		.position(|line| closes(line))
+		.map_or(lines.len(), |i| start + 1 + i);
+
+	let block = &lines[(start + 1)..end];
+	if block
+		.iter()
+		.any(|line| key(line).as_ref() == Some(&entry_key))
+	{
+		return None;
+	}
+
+	let index = block
+		.iter()
+		.position(|line| key(line).is_some_and(|key| key > entry_key))
+		.map(|i| start + 1 + i)
+		.unwrap_or(end);
+
+	lines.insert(index, entry);
+
+	let mut out = lines.join("\n");
+	if text.ends_with('\n') {
+		out.push('\n');
+	}
+
+	Some(out)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+	std::fs::read_to_string(path).map_err(|e| format!("Could not read `{}`: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+	std::fs::write(path, contents).map_err(|e| format!("Could not write `{}`: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests_new_day {
+	use super::*;
+
+	const MANIFEST: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_00\",\n    \"day_01\",\n    \"day_03\",\n]\nresolver = \"2\"";
+
+	#[test]
+	fn members_stay_sorted() {
+		assert_eq!(
+			add_member(MANIFEST, "day_02").unwrap(),
+			MANIFEST.replace("    \"day_03\",", "    \"day_02\",\n    \"day_03\",")
+		);
+		assert_eq!(
+			add_member(MANIFEST, "day_25").unwrap(),
+			MANIFEST.replace("    \"day_03\",", "    \"day_03\",\n    \"day_25\",")
+		);
+		assert_eq!(add_member(MANIFEST, "day_01"), None);
+	}
+
+	#[test]
+	fn days_register_in_order() {
+		let registry = include_str!("../../common/src/days.rs");
+		let without_9 = registry.replace("\t\t\t9 => day_09::Day09,\n", "");
+
+		assert_eq!(add_day(&without_9, 9).unwrap(), registry);
+		assert_eq!(
+			add_day(registry, 20).unwrap(),
+			registry.replace(
+				"compare_part_1,\n",
+				"compare_part_1,\n\t\t\t20 => day_20::Day20,\n"
+			)
+		);
+		assert_eq!(add_day(registry, 9), None);
+	}
+
+	#[test]
+	fn dependencies_stay_sorted() {
+		let manifest = concat!(
+			"[package]\nname = \"aoc\"\n\n[dependencies]\ncommon = { path = \"../common\" }\n",
+			"day_03 = { path = \"../day_03\" }\nlog = \"0.4.20\"\n\n[build-dependencies]\ncc = \"1\"\n"
+		);
+
+		assert_eq!(
+			add_dependency(manifest, "day_02").unwrap(),
+			manifest.replace("day_03 =", "day_02 = { path = \"../day_02\" }\nday_03 =")
+		);
+		assert_eq!(
+			add_dependency(&manifest.replace("\nlog = \"0.4.20\"", ""), "day_04").unwrap(),
+			manifest.replace("\nlog = \"0.4.20\"", "").replace(
+				"\n\n[build",
+				"\nday_04 = { path = \"../day_04\" }\n\n[build"
+			)
+		);
+		assert_eq!(add_dependency(manifest, "day_03"), None);
+	}
+
+	#[test]
+	fn generates_from_template() {
+		let root = std::env::temp_dir().join(format!("aoc_new_day_{}", std::process::id()));
+		let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+			.parent()
+			.expect("Workspace root to exist");
+		std::fs::create_dir_all(root.join(TEMPLATE).join("src")).unwrap();
+		std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+		for file in [REGISTRY].into_iter().chain(DEPENDENTS) {
+			let path = root.join(file);
+			std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+			std::fs::copy(workspace.join(file), path).unwrap();
+		}
+
+		for file in ["Cargo.toml", "src/lib.rs", "src/main.rs"] {
+			std::fs::copy(
+				workspace.join(TEMPLATE).join(file),
+				root.join(TEMPLATE).join(file),
+			)
+			.unwrap();
+		}
+
+		let dir = create(&root, 20).unwrap();
+		let file = |path| std::fs::read_to_string(dir.join(path)).unwrap();
+
+		assert!(file("Cargo.toml").contains("name = \"day_20\""));
+		assert!(file("src/lib.rs").contains("pub struct Day20;"));
+		assert!(file("src/lib.rs").contains("mod tests_day_20 {"));
+		assert!(file("src/main.rs").contains("const DAY: u8 = 20;"));
+		assert!(file("src/main.rs").contains("day_20::parse(&input)"));
+		for path in ["Cargo.toml", "src/lib.rs", "src/main.rs"] {
+			assert!(
+				!file(path).contains("00"),
+				"`{path}` to name day 20 throughout"
+			);
+		}
+		assert_eq!(file("src/part_1_input.txt"), "");
+		assert_eq!(file("src/example.txt"), "");
+		assert!(std::fs::read_to_string(root.join("Cargo.toml"))
+			.unwrap()
+			.contains("\"day_20\""));
+		assert!(std::fs::read_to_string(root.join(REGISTRY))
+			.unwrap()
+			.contains("20 => day_20::Day20,"));
+		for dependent in DEPENDENTS {
+			assert!(std::fs::read_to_string(root.join(dependent))
+				.unwrap()
+				.contains("day_20 = { path = \"../day_20\" }"));
+		}
+
+		assert!(create(&root, 20).unwrap_err().ends_with("already exists"));
+		assert!(create(&root, 0).is_err());
+
+		std::fs::remove_dir_all(&root).unwrap();
+	}
+}
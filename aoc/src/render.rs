@@ -0,0 +1,49 @@
+use crate::config::Config;
+use common::error::SolveError;
+use common::render::{self, Colour, Polygon};
+use common::Grid;
+use std::path::Path;
+
+pub enum Image {
+	Cells(Grid<Colour>),
+	Polygon(Polygon),
+}
+
+/// Parse a day's input and draw it for the given part, with the parameters the config gives the day.
+pub type Renderer = fn(&str, u8, &Config) -> Result<Image, SolveError>;
+
+/// The days that know how to draw themselves.
+pub fn find(day: u8) -> Option<Renderer> {
+	let renderer: Renderer = match day {
+		10 => |input, _, _| Ok(Image::Cells(day_10::render(&day_10::parse(input)?))),
+		16 => |input, _, _| Ok(Image::Cells(day_16::render(&day_16::parse(input)?))),
+		17 => |input, part, config| {
+			let grid = day_17::parse(input)?;
+			let image = day_17::render(&grid, part, &config.day_params(17))?;
+
+			Ok(Image::Cells(image))
+		},
+		18 => |input, part, _| Ok(Image::Polygon(day_18::render(&day_18::parse(input)?, part))),
+		_ => return None,
+	};
+
+	Some(renderer)
+}
+
+/// Write the image as PPM or SVG depending on the file extension. Grid cells are drawn `scale` pixels wide and
+/// polygons are scaled so their longest side is `size` pixels.
+pub fn write(image: &Image, path: &Path, scale: usize, size: usize) -> Result<(), String> {
+	let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+	let contents = match (image, extension) {
+		(Image::Cells(grid), "ppm") => render::grid_ppm(grid, scale),
+		(Image::Cells(grid), "svg") => render::grid_svg(grid, scale).into_bytes(),
+		(Image::Polygon(polygon), "ppm") => {
+			render::grid_ppm(&polygon.rasterise(size, Colour::WHITE), 1)
+		}
+		(Image::Polygon(polygon), "svg") => polygon.svg(size).into_bytes(),
+		_ => return Err(format!("`{}` must end in `.ppm` or `.svg`", path.display())),
+	};
+
+	std::fs::write(path, contents).map_err(|e| format!("Could not write `{}`: {e}", path.display()))
+}
@@ -0,0 +1,240 @@
+use crate::config::Config;
+use crate::days::Day;
+use common::input::InputError;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// How the runner reports its results.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum Format {
+	/// Answers as they are found, followed by a summary table.
+	Text,
+	/// A JSON array with one record per day and part.
+	Json,
+}
+
+pub struct PartResult {
+	pub day: u8,
+	pub part: u8,
+	pub answer_type: &'static str,
+	pub answer: Result<String, String>,
+	pub elapsed: Duration,
+}
+
+/// A part's result as it is written in JSON.
+#[derive(Serialize)]
+pub struct Record<'a> {
+	day: u8,
+	part: u8,
+	answer: Option<&'a str>,
+	#[serde(rename = "type")]
+	answer_type: &'static str,
+	elapsed_ms: f64,
+	error: Option<&'a str>,
+}
+
+impl<'a> Record<'a> {
+	pub fn new(result: &'a PartResult) -> Self {
+		Record {
+			day: result.day,
+			part: result.part,
+			answer: result.answer.as_deref().ok(),
+			answer_type: result.answer_type,
+			elapsed_ms: result.elapsed.as_secs_f64() * 1000.0,
+			error: result.answer.as_ref().err().map(|e| e.as_str()),
+		}
+	}
+}
+
+/// Run the requested parts of each day. In text mode every answer is printed as soon as it is known. A day that panics
+/// fails only the part it panicked on.
+pub fn run(
+	days: &[(&Day, Result<String, InputError>)],
+	parts: &[u8],
+	format: Format,
+	config: &Config,
+) -> Vec<PartResult> {
+	days.iter()
+		.flat_map(|(day, input)| {
+			if let (Err(e), Format::Text) = (input, format) {
+				eprintln!("{e}");
+			}
+
+			parts.iter().map(move |part| {
+				let (answer, elapsed) = match input {
+					Ok(input) => {
+						let now = Instant::now();
+						let answer = day.solve_caught(*part, input, config);
+
+						(answer, now.elapsed())
+					}
+					Err(e) => (Err(e.to_string()), Duration::ZERO),
+				};
+
+				match (&answer, format, input.is_ok()) {
+					(Ok(answer), Format::Text, _) => {
+						println!("Day {:02}, part {part}: {answer}", day.number)
+					}
+					(Err(e), Format::Text, true) => {
+						eprintln!("Day {:02}, part {part}: {e}", day.number)
+					}
+					_ => {}
+				}
+
+				PartResult {
+					day: day.number,
+					part: *part,
+					answer_type: day.answer_type(*part),
+					answer,
+					elapsed,
+				}
+			})
+		})
+		.collect()
+}
+
+pub fn print_json(results: &[PartResult]) {
+	println!("{}", to_json(results));
+}
+
+fn to_json(results: &[PartResult]) -> String {
+	let records = results.iter().map(Record::new).collect::<Vec<_>>();
+
+	serde_json::to_string_pretty(&records).expect("Records to serialize")
+}
+
+pub fn print_summary(results: &[PartResult]) {
+	if results.is_empty() {
+		return;
+	}
+
+	let answer_width = results
+		.iter()
+		.map(|r| summary_answer(r).len())
+		.max()
+		.unwrap_or(0)
+		.max("Answer".len());
+
+	let rows = results
+		.iter()
+		.map(|r| {
+			(
+				format!("{:02}", r.day),
+				r.part.to_string(),
+				summary_answer(r),
+				format_duration(r.elapsed),
+			)
+		})
+		.collect::<Vec<_>>();
+
+	let total = results.iter().map(|r| r.elapsed).sum::<Duration>();
+	let time_width = rows
+		.iter()
+		.map(|r| r.3.len())
+		.chain([format_duration(total).len(), "Time".len()])
+		.max()
+		.unwrap_or(0);
+
+	println!();
+	println!(
+		" Day | Part | {:>answer_width$} | {:>time_width$}",
+		"Answer", "Time"
+	);
+	let separator = format!(
+		"-----+------+-{}-+-{}",
+		"-".repeat(answer_width),
+		"-".repeat(time_width)
+	);
+	println!("{separator}");
+
+	for (day, part, answer, time) in rows {
+		println!("  {day} | {part:>4} | {answer:>answer_width$} | {time:>time_width$}");
+	}
+
+	println!("{separator}");
+	println!(
+		"     |      | {:>answer_width$} | {:>time_width$}",
+		"Total",
+		format_duration(total)
+	);
+}
+
+fn summary_answer(result: &PartResult) -> String {
+	match &result.answer {
+		Ok(answer) => answer.clone(),
+		Err(_) => "error".to_string(),
+	}
+}
+
+pub fn format_duration(duration: Duration) -> String {
+	format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests_run {
+	use super::*;
+	use crate::days;
+	use serde_json::{json, Value};
+
+	#[test]
+	fn panics_fail_only_their_part() {
+		let day = &days::DAYS[0];
+		let days = [
+			(day, Ok("1abc2\nno digits here".to_string())),
+			(day, Ok("1abc2".to_string())),
+		];
+
+		let results = run(&days, &[1], Format::Json, &Config::default());
+		let answers = results.iter().map(|r| r.answer.clone()).collect::<Vec<_>>();
+
+		assert_eq!(answers.len(), 2);
+		assert!(answers[0]
+			.as_ref()
+			.is_err_and(|e| e.starts_with("the solver panicked: ")));
+		assert_eq!(answers[1], Ok("12".to_string()));
+	}
+
+	#[test]
+	fn json_records() {
+		let results = [
+			PartResult {
+				day: 1,
+				part: 2,
+				answer_type: "u32",
+				answer: Ok("281".to_string()),
+				elapsed: Duration::from_micros(1500),
+			},
+			PartResult {
+				day: 13,
+				part: 1,
+				answer_type: "usize",
+				answer: Err("could not parse input: line 2".to_string()),
+				elapsed: Duration::ZERO,
+			},
+		];
+
+		let records = serde_json::from_str::<Value>(&to_json(&results)).unwrap();
+
+		assert_eq!(
+			records,
+			json!([
+				{
+					"day": 1,
+					"part": 2,
+					"answer": "281",
+					"type": "u32",
+					"elapsed_ms": 1.5,
+					"error": null,
+				},
+				{
+					"day": 13,
+					"part": 1,
+					"answer": null,
+					"type": "usize",
+					"elapsed_ms": 0.0,
+					"error": "could not parse input: line 2",
+				},
+			])
+		);
+	}
+}
@@ -0,0 +1,221 @@
+use crate::config::Config;
+use crate::days;
+use crate::run::{PartResult, Record};
+use serde_json::json;
+use std::io::Read;
+use std::panic::AssertUnwindSafe;
+use std::time::Instant;
+use tiny_http::{Header, Method, Request, Server};
+
+/// Inputs larger than this are refused rather than read into memory.
+const MAX_INPUT_BYTES: usize = 16 * 1024 * 1024;
+
+/// A status code with a JSON body.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Response {
+	pub status: u16,
+	pub body: String,
+}
+
+impl Response {
+	fn error(status: u16, message: impl Into<String>) -> Self {
+		Self {
+			status,
+			body: json!({ "error": message.into() }).to_string(),
+		}
+	}
+}
+
+/// Answer requests until the process is stopped, handling up to `workers` at once.
+pub fn serve(server: &Server, workers: usize, config: &Config) {
+	std::thread::scope(|scope| {
+		for _ in 0..workers {
+			scope.spawn(|| {
+				for request in server.incoming_requests() {
+					respond(request, config);
+				}
+			});
+		}
+	});
+}
+
+fn respond(mut request: Request, config: &Config) {
+	let response = match read_body(&mut request) {
+		Ok(body) => handle(request.method(), request.url(), &body, config),
+		Err(response) => response,
+	};
+
+	log::info!(
+		"{} {} -> {}",
+		request.method(),
+		request.url(),
+		response.status
+	);
+
+	let header = Header::from_bytes("Content-Type", "application/json")
+		.expect("Content type header to be valid");
+	let reply = tiny_http::Response::from_string(response.body)
+		.with_status_code(response.status)
+		.with_header(header);
+
+	if let Err(e) = request.respond(reply) {
+		log::warn!("Could not send response: {e}");
+	}
+}
+
+fn read_body(request: &mut Request) -> Result<String, Response> {
+	if request
+		.body_length()
+		.is_some_and(|length| length > MAX_INPUT_BYTES)
+	{
+		return Err(Response::error(413, "input is too large"));
+	}
+
+	let mut body = vec![];
+	request
+		.as_reader()
+		.take(MAX_INPUT_BYTES as u64 + 1)
+		.read_to_end(&mut body)
+		.map_err(|e| Response::error(400, format!("could not read input: {e}")))?;
+
+	if body.len() > MAX_INPUT_BYTES {
+		return Err(Response::error(413, "input is too large"));
+	}
+
+	String::from_utf8(body).map_err(|_| Response::error(400, "input is not valid UTF-8"))
+}
+
+/// Route a request to `POST /day/{n}/part/{p}`, solving the body as the day's input.
+pub fn handle(method: &Method, url: &str, body: &str, config: &Config) -> Response {
+	let path = url.split('?').next().unwrap_or_default();
+	let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+	let ["day", day, "part", part] = segments.as_slice() else {
+		return Response::error(404, "expected `/day/{n}/part/{p}`");
+	};
+
+	let Some(day) = day.parse().ok().and_then(days::find) else {
+		return Response::error(404, format!("no solution exists for day `{day}`"));
+	};
+
+	let part = match *part {
+		"1" => 1,
+		"2" => 2,
+		_ => return Response::error(404, format!("no part `{part}`, expected `1` or `2`")),
+	};
+
+	if *method != Method::Post {
+		return Response::error(405, "expected a POST with the input as the body");
+	}
+
+	let now = Instant::now();
+	let answer =
+		match std::panic::catch_unwind(AssertUnwindSafe(|| day.solve_with(part, body, config))) {
+			Ok(Ok(answer)) => answer,
+			Ok(Err(e)) => return Response::error(422, e.to_string()),
+			Err(_) => return Response::error(500, "the solver panicked on this input"),
+		};
+
+	let result = PartResult {
+		day: day.number,
+		part,
+		answer_type: day.answer_type(part),
+		answer: Ok(answer),
+		elapsed: now.elapsed(),
+	};
+
+	Response {
+		status: 200,
+		body: serde_json::to_string(&Record::new(&result)).expect("Record to serialize"),
+	}
+}
+
+#[cfg(test)]
+mod tests_serve {
+	use super::*;
+	use serde_json::Value;
+	use std::io::Write;
+	use std::net::TcpStream;
+
+	fn post(url: &str, body: &str) -> (u16, Value) {
+		let response = handle(&Method::Post, url, body, &Config::default());
+
+		(
+			response.status,
+			serde_json::from_str(&response.body).unwrap(),
+		)
+	}
+
+	#[test]
+	fn solves_posted_input() {
+		let (status, body) = post("/day/1/part/2", "two1nine\neightwothree\r\n");
+
+		assert_eq!(status, 200);
+		assert_eq!(body["day"], 1);
+		assert_eq!(body["part"], 2);
+		assert_eq!(body["answer"], "112");
+		assert_eq!(body["type"], "u32");
+		assert!(body["elapsed_ms"].is_f64());
+		assert_eq!(body["error"], Value::Null);
+	}
+
+	#[test]
+	fn parse_errors_are_client_errors() {
+		let (status, body) = post("/day/5/part/1", "seeds 1 2");
+
+		assert_eq!(status, 422);
+		assert_eq!(
+			body["error"],
+			"could not parse input: line 1, column 10: expected `:` after `seeds`, found end of line"
+		);
+	}
+
+	#[test]
+	fn solves_with_config() {
+		let mut config = Config::default();
+		config.set("day_02.blue=15").unwrap();
+		let input = "Game 1: 15 blue\nGame 2: 16 blue";
+
+		let response = handle(&Method::Post, "/day/2/part/1", input, &config);
+		let body: Value = serde_json::from_str(&response.body).unwrap();
+
+		assert_eq!(body["answer"], "1");
+		assert_eq!(post("/day/2/part/1", input).1["answer"], "0");
+	}
+
+	#[test]
+	fn unknown_routes() {
+		assert_eq!(post("/day/1", "").0, 404);
+		assert_eq!(post("/day/25/part/1", "").0, 404);
+		assert_eq!(post("/day/one/part/1", "").0, 404);
+		assert_eq!(post("/day/1/part/3", "").0, 404);
+		assert_eq!(
+			handle(&Method::Get, "/day/1/part/1", "", &Config::default()).status,
+			405
+		);
+	}
+
+	#[test]
+	fn over_http() {
+		let server = Server::http("127.0.0.1:0").unwrap();
+		let address = server.server_addr().to_ip().unwrap();
+
+		std::thread::spawn(move || respond(server.recv().unwrap(), &Config::default()));
+
+		let body = "1abc2\npqr3stu8vwx";
+		let mut stream = TcpStream::connect(address).unwrap();
+		write!(
+			stream,
+			"POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+			body.len()
+		)
+		.unwrap();
+
+		let mut response = String::new();
+		stream.read_to_string(&mut response).unwrap();
+
+		assert!(response.starts_with("HTTP/1.1 200"));
+		assert!(response.contains("Content-Type: application/json"));
+		assert!(response.contains(r#""answer":"50""#));
+	}
+}
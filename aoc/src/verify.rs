@@ -0,0 +1,128 @@
+use crate::answers::Answers;
+use crate::days::Day;
+
+#[derive(Debug, Default)]
+pub struct Report {
+	pub passed: usize,
+	pub failed: usize,
+	pub missing: usize,
+	pub recorded: usize,
+}
+
+impl Report {
+	/// The status to exit with: 1 when any part failed, 0 otherwise. Parts with no recorded answer don't fail.
+	pub fn exit_code(&self) -> i32 {
+		match self.failed {
+			0 => 0,
+			_ => 1,
+		}
+	}
+}
+
+/// Solve both parts of each day and compare them against the recorded answers. With `record`, answers for parts
+/// that have none yet are added to `answers`; answers that disagree are never overwritten.
+pub fn verify(days: &[(&Day, String)], answers: &mut Answers, record: bool) -> Report {
+	let mut report = Report::default();
+
+	for (day, input) in days {
+		for part in [1, 2] {
+			let prefix = format!("Day {:02}, part {part}", day.number);
+
+			let answer = match day.solve(part, input) {
+				Ok(answer) => answer,
+				Err(e) => {
+					println!("{prefix}: FAIL, {e}");
+					report.failed += 1;
+					continue;
+				}
+			};
+
+			match answers.get(day.number, part) {
+				Some(expected) if expected == answer => {
+					println!("{prefix}: pass");
+					report.passed += 1;
+				}
+				Some(expected) => {
+					println!("{prefix}: FAIL, expected {expected} but got {answer}");
+					report.failed += 1;
+				}
+				None if record => {
+					println!("{prefix}: recorded {answer}");
+					answers.insert(day.number, part, answer);
+					report.recorded += 1;
+				}
+				None => {
+					println!("{prefix}: missing, got {answer}");
+					report.missing += 1;
+				}
+			}
+		}
+	}
+
+	report
+}
+
+pub fn print_summary(report: &Report) {
+	println!();
+	println!(
+		"{} passed, {} failed, {} missing, {} recorded",
+		report.passed, report.failed, report.missing, report.recorded
+	);
+}
+
+#[cfg(test)]
+mod tests_verify {
+	use super::*;
+	use crate::days;
+
+	const INPUT: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+
+	fn check(answers: &str, record: bool) -> (Report, Answers) {
+		let days = [(days::find(1).unwrap(), INPUT.to_string())];
+		let mut answers = Answers::parse(answers).unwrap();
+		let report = verify(&days, &mut answers, record);
+
+		(report, answers)
+	}
+
+	#[test]
+	fn matching_answers_pass() {
+		let (report, _) = check("01 1 142\n01 2 142", false);
+
+		assert_eq!((report.passed, report.failed, report.missing), (2, 0, 0));
+		assert_eq!(report.exit_code(), 0);
+	}
+
+	#[test]
+	fn wrong_answers_fail_and_stay() {
+		let (report, answers) = check("01 1 141\n01 2 142", true);
+
+		assert_eq!((report.passed, report.failed, report.recorded), (1, 1, 0));
+		assert_eq!(answers.get(1, 1), Some("141"));
+		assert_eq!(report.exit_code(), 1);
+	}
+
+	#[test]
+	fn unsolvable_inputs_fail() {
+		let days = [(days::find(2).unwrap(), "Game one: 3 blue".to_string())];
+		let report = verify(&days, &mut Answers::default(), true);
+
+		assert_eq!((report.failed, report.recorded), (2, 0));
+		assert_eq!(report.exit_code(), 1);
+	}
+
+	#[test]
+	fn missing_answers_pass_unless_recorded() {
+		let (report, answers) = check("01 1 142", false);
+
+		assert_eq!((report.passed, report.missing, report.recorded), (1, 1, 0));
+		assert_eq!(answers.get(1, 2), None);
+		assert_eq!(report.exit_code(), 0);
+
+		let (report, answers) = check("01 1 142", true);
+
+		assert_eq!((report.passed, report.missing, report.recorded), (1, 0, 1));
+		assert_eq!(answers.get(1, 2), Some("142"));
+		assert_eq!(report.exit_code(), 0);
+	}
+}
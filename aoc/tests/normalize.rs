@@ -0,0 +1,94 @@
+use common::error::NoAnswer;
+use common::input::{self, InputSource};
+use common::rng::Rng;
+use common::solution::Answer;
+use common::Solution;
+use std::path::PathBuf;
+
+/// Both answers for a day's input.
+type Solver = fn(&str) -> (String, String);
+
+type Generator = fn(&mut Rng, usize) -> String;
+
+fn solve<S: Solution>(input: &str) -> (String, String) {
+	let input = S::parse(input).expect("Input to parse");
+
+	let answer = |answer: Result<String, NoAnswer>| answer.expect("Input to have an answer");
+
+	(
+		answer(S::part_1(&input).into_answer()),
+		answer(S::part_2(&input).into_answer()),
+	)
+}
+
+macro_rules! days {
+	($($number:literal => $krate:ident::$solution:ident $(checked by $compare:ident)?),* $(,)?) => {
+		const DAYS: &[(u8, Solver, Generator)] = &[$(($number, solve::<$krate::$solution>, $krate::generate)),*];
+	};
+}
+
+common::days!(days);
+
+/// The same input as saved by different editors: with a final newline, with Windows line endings, with a byte order
+/// mark, and with stray trailing whitespace.
+fn variants(input: &str) -> [(&'static str, String); 4] {
+	let lf = format!("{input}\n");
+
+	[
+		("lf", lf.clone()),
+		("crlf", lf.replace('\n', "\r\n")),
+		("bom", format!("\u{feff}{input}")),
+		("trailing", lf.replace('\n', " \t\n") + "\n\n"),
+	]
+}
+
+/// Write each variant of `input` to a directory for the test, returning its name and path.
+fn write_variants(test: &str, day: u8, input: &str) -> Vec<(&'static str, PathBuf)> {
+	let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(test);
+	std::fs::create_dir_all(&dir).expect("Temporary directory to be created");
+
+	variants(input)
+		.into_iter()
+		.map(|(name, variant)| {
+			let path = dir.join(format!("day_{day:02}_{name}.txt"));
+			std::fs::write(&path, variant).expect("Variant to be written");
+
+			(name, path)
+		})
+		.collect()
+}
+
+#[test]
+fn line_endings_do_not_change_answers() {
+	for &(day, solve, generate) in DAYS {
+		// Generated inputs keep this quick in debug builds, where some days take seconds on the real input.
+		let input = generate(&mut Rng::new(u64::from(day)), 8);
+		let expected = solve(&input);
+
+		for (name, path) in write_variants("answers", day, &input) {
+			let loaded = input::load(day, &InputSource::Path(path)).expect("Variant to load");
+
+			assert_eq!(
+				solve(&loaded),
+				expected,
+				"day {day:02} answers changed for the {name} variant"
+			);
+		}
+	}
+}
+
+#[test]
+fn puzzle_inputs_load_the_same_however_saved() {
+	for &(day, _, _) in DAYS {
+		let expected = input::load(day, &InputSource::Default).expect("Input to load");
+
+		for (name, path) in write_variants("puzzle_inputs", day, &expected) {
+			let loaded = input::load(day, &InputSource::Path(path)).expect("Variant to load");
+
+			assert!(
+				loaded == expected,
+				"day {day:02} input loaded differently from the {name} variant"
+			);
+		}
+	}
+}
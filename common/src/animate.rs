@@ -0,0 +1,202 @@
+use crate::{Grid, Point};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, Write};
+use std::time::{Duration, Instant};
+
+/// Receives each intermediate state of a simulation, with `true` for the final state. Frames are formatted lazily, so
+/// only the ones that are shown cost anything to draw.
+pub type OnFrame<'a> = &'a mut dyn FnMut(&dyn Display, bool);
+
+/// Redraws frames in place on the terminal using ANSI escapes.
+pub struct Animation {
+	delay: Duration,
+	step: bool,
+	every: usize,
+	frames: usize,
+	last_drawn: Option<Instant>,
+	terminal: Option<Terminal>,
+}
+
+impl Animation {
+	/// Show every `every`th frame and the last one at `fps` frames per second, or wait for a key press between frames
+	/// when `step` is set. Ctrl-C stops the animation and exits.
+	pub fn new(fps: f64, step: bool, every: usize) -> Self {
+		Self {
+			delay: Duration::from_secs_f64(1.0 / fps.max(0.001)),
+			step,
+			every: every.max(1),
+			frames: 0,
+			last_drawn: None,
+			terminal: None,
+		}
+	}
+
+	pub fn frame(&mut self, frame: &dyn Display, last: bool) {
+		let index = self.frames;
+		self.frames += 1;
+
+		if !last && !index.is_multiple_of(self.every) {
+			return;
+		}
+
+		let terminal = self.terminal.get_or_insert_with(Terminal::new);
+
+		if let Some(last_drawn) = self.last_drawn {
+			let deadline = match self.step {
+				true => None,
+				false => Some(last_drawn + self.delay),
+			};
+
+			if !terminal.wait(deadline) {
+				self.terminal = None;
+				std::process::exit(130);
+			}
+		}
+
+		let prompt = match self.step {
+			true => "\nPress any key for the next frame",
+			false => "",
+		};
+		let output = format!("\x1b[H{frame}\x1b[J\nFrame {}{prompt}", self.frames);
+
+		terminal.write(&output);
+		self.last_drawn = Some(Instant::now());
+	}
+
+	/// Restore the terminal and move below the last frame.
+	pub fn finish(&mut self) {
+		self.terminal = None;
+	}
+}
+
+/// Holds the terminal in raw mode with the cursor hidden while an animation is shown, so single key presses can be
+/// read, and restores it when dropped, including when Ctrl-C stops the animation or a solver panics.
+struct Terminal {
+	raw: bool,
+}
+
+impl Terminal {
+	fn new() -> Self {
+		// Without a terminal to read keys from, fall back to reading lines from stdin.
+		let raw = crossterm::terminal::enable_raw_mode().is_ok();
+
+		let mut stdout = std::io::stdout().lock();
+		let _ = write!(stdout, "\x1b[2J\x1b[?25l");
+		let _ = stdout.flush();
+
+		Self { raw }
+	}
+
+	fn write(&self, output: &str) {
+		let output = match self.raw {
+			// Raw mode no longer turns `\n` into a new line at the start of the row.
+			true => output.replace('\n', "\r\n"),
+			false => output.to_string(),
+		};
+
+		let mut stdout = std::io::stdout().lock();
+		let _ = write!(stdout, "{output}");
+		let _ = stdout.flush();
+	}
+
+	/// Wait for a key press, or until `deadline` when there is one. Returns `false` if the key was Ctrl-C.
+	fn wait(&self, deadline: Option<Instant>) -> bool {
+		if !self.raw {
+			match deadline {
+				Some(deadline) => {
+					std::thread::sleep(deadline.saturating_duration_since(Instant::now()))
+				}
+				None => {
+					let mut line = String::new();
+					let _ = std::io::stdin().lock().read_line(&mut line);
+				}
+			}
+
+			return true;
+		}
+
+		loop {
+			if let Some(deadline) = deadline {
+				let remaining = deadline.saturating_duration_since(Instant::now());
+
+				if !event::poll(remaining).unwrap_or(false) {
+					return true;
+				}
+			}
+
+			match event::read() {
+				Ok(Event::Key(KeyEvent {
+					code: KeyCode::Char('c'),
+					modifiers,
+					kind: KeyEventKind::Press,
+					..
+				})) if modifiers.contains(KeyModifiers::CONTROL) => return false,
+				Ok(Event::Key(KeyEvent {
+					kind: KeyEventKind::Press,
+					..
+				})) if deadline.is_none() => return true,
+				Ok(_) => {}
+				Err(_) => return true,
+			}
+		}
+	}
+}
+
+impl Drop for Terminal {
+	fn drop(&mut self) {
+		if self.raw {
+			let _ = crossterm::terminal::disable_raw_mode();
+		}
+
+		println!("\x1b[?25h");
+	}
+}
+
+/// Formats a grid one character per cell, where `cell` picks the character for each position.
+pub struct Canvas<'a, T, F> {
+	grid: &'a Grid<T>,
+	cell: F,
+}
+
+impl<'a, T, F: Fn(Point<usize>, &T) -> char> Canvas<'a, T, F> {
+	pub fn new(grid: &'a Grid<T>, cell: F) -> Self {
+		Self { grid, cell }
+	}
+}
+
+impl<T, F: Fn(Point<usize>, &T) -> char> Display for Canvas<'_, T, F> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		for (y, row) in self.grid.rows().enumerate() {
+			if y > 0 {
+				writeln!(f)?;
+			}
+
+			let line = row
+				.iter()
+				.enumerate()
+				.map(|(x, value)| (self.cell)(Point::new(x, y), value))
+				.collect::<String>();
+
+			f.write_str(&line)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests_animate {
+	use super::*;
+
+	#[test]
+	fn canvas_draws_each_cell() {
+		let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+		let canvas = Canvas::new(&grid, |point, value| match point == Point::new(1, 1) {
+			true => '@',
+			false => char::from_digit(*value, 10).unwrap(),
+		});
+
+		assert_eq!(canvas.to_string(), "123\n4@6");
+	}
+}
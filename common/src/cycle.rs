@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states, each made by stepping the last, starts repeating. The state at index `start` is the
+/// first one inside the loop, and the state at `start + length` is the same state again.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cycle {
+	pub start: usize,
+	pub length: usize,
+}
+
+impl Cycle {
+	/// The earliest index whose state is the same as the state at `index`.
+	pub fn reduce(&self, index: usize) -> usize {
+		match index < self.start {
+			true => index,
+			false => self.start + (index - self.start) % self.length,
+		}
+	}
+}
+
+/// Find the cycle with Brent's algorithm, which keeps only two states at a time and steps fewer times than Floyd's.
+pub fn brent<T: PartialEq + Clone>(initial: &T, step: impl Fn(&T) -> T) -> Cycle {
+	// 1. Find the length by letting the hare run ahead of a tortoise that teleports to it at each power of two.
+	let mut power = 1;
+	let mut length = 1;
+	let mut tortoise = initial.clone();
+	let mut hare = step(initial);
+
+	while tortoise != hare {
+		if power == length {
+			tortoise = hare.clone();
+			power *= 2;
+			length = 0;
+		}
+
+		hare = step(&hare);
+		length += 1;
+	}
+
+	// 2. Find the start by walking two states `length` apart until they meet.
+	let mut tortoise = initial.clone();
+	let mut hare = initial.clone();
+	for _ in 0..length {
+		hare = step(&hare);
+	}
+
+	let mut start = 0;
+	while tortoise != hare {
+		tortoise = step(&tortoise);
+		hare = step(&hare);
+		start += 1;
+	}
+
+	Cycle { start, length }
+}
+
+/// Find the cycle with Floyd's tortoise and hare, which keeps only two states at a time.
+pub fn floyd<T: PartialEq + Clone>(initial: &T, step: impl Fn(&T) -> T) -> Cycle {
+	// 1. Find a meeting point inside the loop, with the hare moving twice as fast.
+	let mut tortoise = step(initial);
+	let mut hare = step(&tortoise);
+
+	while tortoise != hare {
+		tortoise = step(&tortoise);
+		hare = step(&step(&hare));
+	}
+
+	// 2. The start is as far from the initial state as it is from the meeting point.
+	let mut start = 0;
+	let mut tortoise = initial.clone();
+	while tortoise != hare {
+		tortoise = step(&tortoise);
+		hare = step(&hare);
+		start += 1;
+	}
+
+	// 3. Walk once around the loop to measure it.
+	let mut length = 1;
+	let mut hare = step(&tortoise);
+	while tortoise != hare {
+		hare = step(&hare);
+		length += 1;
+	}
+
+	Cycle { start, length }
+}
+
+/// Find the cycle by remembering every state, which steps each state only once. The states up to the end of the first
+/// loop are returned too, in order.
+pub fn hashed<T: Hash + Eq + Clone>(initial: &T, step: impl Fn(&T) -> T) -> (Cycle, Vec<T>) {
+	let mut seen = HashMap::new();
+	let mut states = vec![initial.clone()];
+
+	loop {
+		let index = states.len() - 1;
+		let state = &states[index];
+
+		if let Some(start) = seen.insert(state.clone(), index) {
+			states.pop();
+
+			let cycle = Cycle {
+				start,
+				length: index - start,
+			};
+
+			return (cycle, states);
+		}
+
+		states.push(step(state));
+	}
+}
+
+/// The state after stepping `initial` `target` times, found with [`brent`] so only a couple of states are kept.
+pub fn state_at<T: PartialEq + Clone>(initial: T, step: impl Fn(&T) -> T, target: usize) -> T {
+	let cycle = brent(&initial, &step);
+
+	(0..cycle.reduce(target)).fold(initial, |state, _| step(&state))
+}
+
+/// The state after stepping `initial` `target` times, found with [`hashed`] so no state is stepped twice.
+pub fn state_at_hashed<T: Hash + Eq + Clone>(
+	initial: T,
+	step: impl Fn(&T) -> T,
+	target: usize,
+) -> T {
+	let (cycle, mut states) = hashed(&initial, step);
+
+	states.swap_remove(cycle.reduce(target))
+}
+
+#[cfg(test)]
+mod tests_cycle {
+	use super::*;
+
+	/// Reaches 5 after a tail of two states, then loops through 5, 6, 7 and 8.
+	fn step(state: &u64) -> u64 {
+		match state {
+			8 => 5,
+			_ => state + 1,
+		}
+	}
+
+	#[test]
+	fn methods_agree() {
+		let expected = Cycle {
+			start: 2,
+			length: 4,
+		};
+
+		assert_eq!(brent(&3, step), expected);
+		assert_eq!(floyd(&3, step), expected);
+		assert_eq!(hashed(&3, step).0, expected);
+		assert_eq!(hashed(&3, step).1, vec![3, 4, 5, 6, 7, 8]);
+	}
+
+	#[test]
+	fn loop_from_the_start() {
+		let expected = Cycle {
+			start: 0,
+			length: 1,
+		};
+
+		assert_eq!(brent(&7, |n| *n), expected);
+		assert_eq!(floyd(&7, |n| *n), expected);
+		assert_eq!(hashed(&7, |n| *n).0, expected);
+	}
+
+	#[test]
+	fn states_match_stepping() {
+		for target in 0..40 {
+			let stepped = (0..target).fold(3, |state, _| step(&state));
+
+			assert_eq!(state_at(3, step, target), stepped);
+			assert_eq!(state_at_hashed(3, step, target), stepped);
+		}
+
+		assert_eq!(
+			state_at(3, step, 1_000_000_000),
+			5 + (1_000_000_000 - 2) % 4
+		);
+	}
+
+	#[test]
+	fn random_maps() {
+		// Sequences from the squaring map used by Pollard's rho, with a mix of tail and loop lengths.
+		for modulus in 2..200 {
+			let step = |n: &u64| (n * n + 1) % modulus;
+
+			let cycle = brent(&0, step);
+			assert_eq!(floyd(&0, step), cycle);
+			assert_eq!(hashed(&0, step).0, cycle);
+		}
+	}
+}
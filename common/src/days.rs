@@ -0,0 +1,41 @@
+/// The one list of solved days. Each crate that needs every day expands its own table from it by passing the name of a
+/// macro, which is called with the whole list:
+///
+/// ```ignore
+/// macro_rules! solvers {
+///     ($($number:literal => $krate:ident::$solution:ident $(checked by $compare:ident)?),* $(,)?) => {
+///         // A match, an array or a function over `$number`, `$krate` and `$solution`.
+///     };
+/// }
+///
+/// common::days!(solvers);
+/// ```
+///
+/// Every day crate has a `generate` function for random inputs. A day that keeps a slower reference solver around
+/// names the function comparing the two with `checked by`. `aoc new-day` adds new days here.
+#[macro_export]
+macro_rules! days {
+	($table:ident) => {
+		$table! {
+			1 => day_01::Day01,
+			2 => day_02::Day02,
+			3 => day_03::Day03,
+			4 => day_04::Day04,
+			5 => day_05::Day05 checked by compare_part_2,
+			6 => day_06::Day06 checked by compare_ways_to_win,
+			7 => day_07::Day07,
+			8 => day_08::Day08,
+			9 => day_09::Day09,
+			10 => day_10::Day10 checked by compare_part_2,
+			11 => day_11::Day11,
+			12 => day_12::Day12,
+			13 => day_13::Day13,
+			14 => day_14::Day14,
+			15 => day_15::Day15,
+			16 => day_16::Day16,
+			17 => day_17::Day17,
+			18 => day_18::Day18 checked by compare_areas,
+			19 => day_19::Day19 checked by compare_part_1,
+		}
+	};
+}
@@ -0,0 +1,166 @@
+use crate::rng::Rng;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
+
+/// The first generated input on which two solvers disagreed, shrunk to as little input as still shows it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+	pub seed: u64,
+	pub input: String,
+	pub reference: String,
+	pub candidate: String,
+}
+
+impl Display for Mismatch {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"seed {}: the reference gave {} but the candidate gave {} for\n{}",
+			self.seed, self.reference, self.candidate, self.input
+		)
+	}
+}
+
+/// Generate an input of the given size for each seed and solve it with `solve`, which returns the reference answer
+/// and the candidate's, or `None` when the input isn't valid.
+pub fn check<T: PartialEq + Debug>(
+	generate: fn(&mut Rng, usize) -> String,
+	size: usize,
+	seeds: Range<u64>,
+	solve: impl Fn(&str) -> Option<(T, T)>,
+) -> Result<(), Mismatch> {
+	for seed in seeds {
+		let input = generate(&mut Rng::new(seed), size);
+
+		if disagrees(&input, &solve) {
+			let input = shrink(&input, &solve);
+			let (reference, candidate) = solve(&input).expect("Shrunk input to still be valid");
+
+			return Err(Mismatch {
+				seed,
+				input,
+				reference: format!("{reference:?}"),
+				candidate: format!("{candidate:?}"),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+/// Remove runs of lines, then space separated tokens from each remaining line, for as long as the solvers still
+/// disagree.
+pub fn shrink<T: PartialEq>(input: &str, solve: impl Fn(&str) -> Option<(T, T)>) -> String {
+	let mut lines = input.lines().map(String::from).collect::<Vec<_>>();
+	let mut chunk = (lines.len() / 2).max(1);
+
+	loop {
+		let mut i = 0;
+
+		while i < lines.len() {
+			let end = (i + chunk).min(lines.len());
+			let smaller = [&lines[..i], &lines[end..]].concat();
+
+			match disagrees(&smaller.join("\n"), &solve) {
+				true => lines = smaller,
+				false => i += chunk,
+			}
+		}
+
+		if chunk == 1 {
+			break;
+		}
+
+		chunk /= 2;
+	}
+
+	for y in 0..lines.len() {
+		let mut i = 0;
+
+		loop {
+			let tokens = lines[y].split(' ').collect::<Vec<_>>();
+
+			if i >= tokens.len() {
+				break;
+			}
+
+			let line = [&tokens[..i], &tokens[i + 1..]].concat().join(" ");
+			let mut smaller = lines.clone();
+			smaller[y] = line;
+
+			// A blank line is a single empty token, and removing it changes nothing.
+			match smaller[y] != lines[y] && disagrees(&smaller.join("\n"), &solve) {
+				true => lines = smaller,
+				false => i += 1,
+			}
+		}
+	}
+
+	lines.join("\n")
+}
+
+fn disagrees<T: PartialEq>(input: &str, solve: &impl Fn(&str) -> Option<(T, T)>) -> bool {
+	solve(input).is_some_and(|(reference, candidate)| reference != candidate)
+}
+
+#[cfg(test)]
+mod tests_differential {
+	use super::*;
+
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		(0..size)
+			.map(|_| {
+				(0..3)
+					.map(|_| rng.range(0..=9).to_string())
+					.collect::<Vec<_>>()
+					.join(" ")
+			})
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	/// Sum every number, with a candidate that forgets about sevens.
+	fn solve(input: &str) -> Option<(u64, u64)> {
+		let numbers = input
+			.split_whitespace()
+			.map(|n| n.parse::<u64>().ok())
+			.collect::<Option<Vec<_>>>()?;
+
+		Some((
+			numbers.iter().sum(),
+			numbers.iter().filter(|n| **n != 7).sum(),
+		))
+	}
+
+	#[test]
+	fn agreeing_solvers_pass() {
+		let agreeing = |input: &str| solve(input).map(|(sum, _)| (sum, sum));
+
+		assert_eq!(check(generate, 10, 0..50, agreeing), Ok(()));
+	}
+
+	#[test]
+	fn blank_lines_are_kept_when_needed() {
+		// The solvers only disagree when a blank line comes before a last line of 7.
+		let solve = |input: &str| {
+			let blank = input.lines().any(str::is_empty);
+			let last = input.lines().last()?.parse::<u64>().ok()?;
+
+			Some((blank && last == 7, false))
+		};
+
+		assert_eq!(shrink("1\n\n2\n7", solve), "\n7");
+	}
+
+	#[test]
+	fn mismatch_is_shrunk() {
+		let mismatch = check(generate, 10, 0..50, solve).unwrap_err();
+
+		assert_eq!(mismatch.seed, 0);
+		assert_eq!(mismatch.input, "7");
+		assert_eq!(
+			(mismatch.reference.as_str(), mismatch.candidate.as_str()),
+			("7", "0")
+		);
+	}
+}
@@ -0,0 +1,203 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An input that could not be parsed, pointing at the offending line and column (both 1-based).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+	pub line: usize,
+	pub column: usize,
+	pub expected: String,
+	pub found: String,
+}
+
+impl ParseError {
+	/// Error for the zero-based `line_index` and `column_index` of the input.
+	pub fn new(
+		line_index: usize,
+		column_index: usize,
+		expected: impl Into<String>,
+		found: impl Display,
+	) -> Self {
+		Self {
+			line: line_index + 1,
+			column: column_index + 1,
+			expected: expected.into(),
+			found: format!("`{found}`"),
+		}
+	}
+
+	/// Error for `found`, which must be a slice of `line`. The column is worked out from where the slice sits, and an
+	/// empty slice at the end of the line reads the same as [`ParseError::end_of_line`].
+	pub fn at(line_index: usize, line: &str, found: &str, expected: impl Into<String>) -> Self {
+		let offset = (found.as_ptr() as usize)
+			.checked_sub(line.as_ptr() as usize)
+			.filter(|offset| *offset <= line.len())
+			.expect("Found to be a slice of line");
+
+		let found = match (found.is_empty(), offset == line.len()) {
+			(true, true) => "end of line".to_string(),
+			(true, false) => "nothing".to_string(),
+			(false, _) => format!("`{found}`"),
+		};
+
+		Self {
+			line: line_index + 1,
+			column: line[..offset].chars().count() + 1,
+			expected: expected.into(),
+			found,
+		}
+	}
+
+	/// Error for a line which stopped before something that was expected.
+	pub fn end_of_line(line_index: usize, line: &str, expected: impl Into<String>) -> Self {
+		Self {
+			line: line_index + 1,
+			column: line.chars().count() + 1,
+			expected: expected.into(),
+			found: "end of line".to_string(),
+		}
+	}
+
+	/// Error for an input which stopped before something that was expected.
+	pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+		Self {
+			line: input.lines().count() + 1,
+			column: 1,
+			expected: expected.into(),
+			found: "end of input".to_string(),
+		}
+	}
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"line {}, column {}: expected {}, found {}",
+			self.line, self.column, self.expected, self.found
+		)
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+/// An input which parsed, but has no answer for a part, such as a network without the node the part starts from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NoAnswer(pub String);
+
+impl Display for NoAnswer {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "the input has no answer: {}", self.0)
+	}
+}
+
+impl std::error::Error for NoAnswer {}
+
+/// Why a part of a day couldn't be solved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolveError {
+	Parse(ParseError),
+	NoAnswer(NoAnswer),
+}
+
+impl Display for SolveError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SolveError::Parse(e) => write!(f, "could not parse input: {e}"),
+			SolveError::NoAnswer(e) => e.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+	fn from(e: ParseError) -> Self {
+		SolveError::Parse(e)
+	}
+}
+
+impl From<NoAnswer> for SolveError {
+	fn from(e: NoAnswer) -> Self {
+		SolveError::NoAnswer(e)
+	}
+}
+
+/// Parse `token`, a slice of `line`, reporting its position if it isn't a valid `T`.
+pub fn parse_token<T: FromStr>(
+	line_index: usize,
+	line: &str,
+	token: &str,
+	expected: &str,
+) -> Result<T, ParseError> {
+	token
+		.parse()
+		.map_err(|_| ParseError::at(line_index, line, token, expected))
+}
+
+/// Unwrap a result, exiting with its error message if it failed.
+pub fn or_exit<T, E: Display>(result: Result<T, E>) -> T {
+	match result {
+		Ok(value) => value,
+		Err(e) => {
+			eprintln!("{e}");
+			std::process::exit(1);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests_error {
+	use super::*;
+
+	#[test]
+	fn column_from_slice() {
+		let line = "Game 1: 3 blue, x red";
+		let token = &line[16..17];
+		let error = ParseError::at(4, line, token, "a number");
+
+		assert_eq!(error.line, 5);
+		assert_eq!(error.column, 17);
+		assert_eq!(
+			error.to_string(),
+			"line 5, column 17: expected a number, found `x`"
+		);
+	}
+
+	#[test]
+	fn column_counts_characters() {
+		let line = "│─x";
+		let error = ParseError::at(0, line, &line[6..], "a pipe");
+
+		assert_eq!(error.column, 3);
+	}
+
+	#[test]
+	fn parse_token_reports_position() {
+		let line = "seeds: 79 1x";
+
+		assert_eq!(parse_token::<u64>(0, line, &line[7..9], "a seed"), Ok(79));
+		assert_eq!(
+			parse_token::<u64>(0, line, &line[10..], "a seed")
+				.expect_err("Token to be invalid")
+				.column,
+			11
+		);
+	}
+
+	#[test]
+	fn end_of_line_and_input() {
+		assert_eq!(
+			ParseError::end_of_line(0, "abc", "`:`").to_string(),
+			"line 1, column 4: expected `:`, found end of line"
+		);
+		assert_eq!(
+			ParseError::at(0, "abc", &"abc"[3..], "`:`"),
+			ParseError::end_of_line(0, "abc", "`:`")
+		);
+		assert_eq!(
+			ParseError::end_of_input("a\nb", "a blank line").to_string(),
+			"line 3, column 1: expected a blank line, found end of input"
+		);
+	}
+}
@@ -0,0 +1,246 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+
+/// A number that can be used as one axis of a [`Point`].
+pub trait Coordinate: Copy + Default + Ord + Add<Output = Self> + Sub<Output = Self> {
+	/// Add a signed `delta`, or `None` if the result doesn't fit, e.g. stepping below zero on an unsigned axis.
+	fn checked_offset(self, delta: i64) -> Option<Self>;
+
+	/// The absolute difference between two coordinates.
+	fn distance(self, other: Self) -> Self {
+		self.max(other) - self.min(other)
+	}
+}
+
+macro_rules! coordinate {
+	($($t:ty),*) => {
+		$(
+			impl Coordinate for $t {
+				fn checked_offset(self, delta: i64) -> Option<Self> {
+					let value = i128::try_from(self).ok()? + i128::from(delta);
+
+					Self::try_from(value).ok()
+				}
+			}
+		)*
+	};
+}
+
+coordinate!(i32, i64, isize, u32, u64, usize);
+
+/// A position on a plane where `x` grows to the east and `y` grows to the south.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point<T> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T> Point<T> {
+	pub const fn new(x: T, y: T) -> Self {
+		Self { x, y }
+	}
+}
+
+impl<T: Coordinate> Point<T> {
+	/// Move by `(dx, dy)`, or `None` if either axis can't hold the result.
+	pub fn offset(self, dx: i64, dy: i64) -> Option<Self> {
+		Some(Self {
+			x: self.x.checked_offset(dx)?,
+			y: self.y.checked_offset(dy)?,
+		})
+	}
+
+	pub fn step(self, direction: Direction) -> Option<Self> {
+		self.step_by(direction, 1)
+	}
+
+	pub fn step_by(self, direction: Direction, distance: i64) -> Option<Self> {
+		let (dx, dy) = direction.offset();
+
+		self.offset(dx * distance, dy * distance)
+	}
+
+	/// Take a single step, or `None` if that leaves the `width` by `height` area starting at the origin.
+	pub fn step_within(self, direction: Direction, width: T, height: T) -> Option<Self> {
+		self.step(direction)
+			.filter(|point| point.is_within(width, height))
+	}
+
+	pub fn is_within(self, width: T, height: T) -> bool {
+		let zero = T::default();
+
+		(zero..width).contains(&self.x) && (zero..height).contains(&self.y)
+	}
+
+	pub fn manhattan(self, other: Self) -> T {
+		self.x.distance(other.x) + self.y.distance(other.y)
+	}
+
+	pub fn chebyshev(self, other: Self) -> T {
+		self.x.distance(other.x).max(self.y.distance(other.y))
+	}
+
+	/// The four orthogonal neighbours, skipping any that can't be represented.
+	pub fn neighbours_4(self) -> impl Iterator<Item = Self> {
+		Direction::ALL
+			.into_iter()
+			.filter_map(move |direction| self.step(direction))
+	}
+
+	/// All eight surrounding neighbours, skipping any that can't be represented.
+	pub fn neighbours_8(self) -> impl Iterator<Item = Self> {
+		(-1..=1)
+			.flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+			.filter(|offset| *offset != (0, 0))
+			.filter_map(move |(dx, dy)| self.offset(dx, dy))
+	}
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+	type Output = Self;
+
+	fn add(self, other: Self) -> Self {
+		Self {
+			x: self.x + other.x,
+			y: self.y + other.y,
+		}
+	}
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+	type Output = Self;
+
+	fn sub(self, other: Self) -> Self {
+		Self {
+			x: self.x - other.x,
+			y: self.y - other.y,
+		}
+	}
+}
+
+impl<T: Display> Display for Point<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "({}, {})", self.x, self.y)
+	}
+}
+
+/// A compass direction, with north pointing towards smaller `y`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+	North,
+	East,
+	South,
+	West,
+}
+
+impl Direction {
+	/// Every direction, clockwise from north.
+	pub const ALL: [Direction; 4] = [
+		Direction::North,
+		Direction::East,
+		Direction::South,
+		Direction::West,
+	];
+
+	pub fn turn_left(self) -> Self {
+		match self {
+			Direction::North => Direction::West,
+			Direction::East => Direction::North,
+			Direction::South => Direction::East,
+			Direction::West => Direction::South,
+		}
+	}
+
+	pub fn turn_right(self) -> Self {
+		match self {
+			Direction::North => Direction::East,
+			Direction::East => Direction::South,
+			Direction::South => Direction::West,
+			Direction::West => Direction::North,
+		}
+	}
+
+	pub fn reverse(self) -> Self {
+		match self {
+			Direction::North => Direction::South,
+			Direction::East => Direction::West,
+			Direction::South => Direction::North,
+			Direction::West => Direction::East,
+		}
+	}
+
+	/// The `(dx, dy)` of a single step.
+	pub fn offset(self) -> (i64, i64) {
+		match self {
+			Direction::North => (0, -1),
+			Direction::East => (1, 0),
+			Direction::South => (0, 1),
+			Direction::West => (-1, 0),
+		}
+	}
+
+	pub fn is_horizontal(self) -> bool {
+		match self {
+			Direction::North | Direction::South => false,
+			Direction::East | Direction::West => true,
+		}
+	}
+
+	pub fn is_vertical(self) -> bool {
+		!self.is_horizontal()
+	}
+}
+
+#[cfg(test)]
+mod tests_geometry {
+	use super::*;
+
+	#[test]
+	fn turning() {
+		for direction in Direction::ALL {
+			assert_eq!(direction.turn_left().turn_right(), direction);
+			assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+			assert_ne!(
+				direction.is_horizontal(),
+				direction.turn_left().is_horizontal()
+			);
+		}
+
+		assert_eq!(Direction::North.turn_right(), Direction::East);
+	}
+
+	#[test]
+	fn stepping() {
+		let origin = Point::<usize>::new(0, 0);
+
+		assert_eq!(origin.step(Direction::North), None);
+		assert_eq!(origin.step(Direction::East), Some(Point::new(1, 0)));
+		assert_eq!(origin.step_within(Direction::East, 1, 1), None);
+		assert_eq!(
+			Point::new(3i64, 4).step_by(Direction::North, 10),
+			Some(Point::new(3, -6))
+		);
+	}
+
+	#[test]
+	fn distances() {
+		let a = Point::new(1i64, -2);
+		let b = Point::new(-3, 5);
+
+		assert_eq!(a.manhattan(b), 11);
+		assert_eq!(a.chebyshev(b), 7);
+		assert_eq!(Point::new(2usize, 9).manhattan(Point::new(5, 1)), 11);
+	}
+
+	#[test]
+	fn neighbours() {
+		let corner = Point::<usize>::new(0, 0);
+
+		assert_eq!(corner.neighbours_4().count(), 2);
+		assert_eq!(
+			corner.neighbours_8().collect::<Vec<_>>(),
+			[Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]
+		);
+		assert_eq!(Point::new(0i64, 0).neighbours_8().count(), 8);
+	}
+}
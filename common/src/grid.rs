@@ -0,0 +1,307 @@
+use crate::geometry::Point;
+use crate::ParseError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row, indexed by `(x, y)` with the origin in the top left.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+	width: usize,
+	height: usize,
+	cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+	pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+		assert_eq!(cells.len(), width * height, "Cells to fill the grid");
+
+		Self {
+			width,
+			height,
+			cells,
+		}
+	}
+
+	/// Build a grid from rows, or `None` if they aren't all the same length.
+	pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+		let width = rows.first().map(|row| row.len()).unwrap_or(0);
+		let height = rows.len();
+
+		if rows.iter().any(|row| row.len() != width) {
+			return None;
+		}
+
+		Some(Self::new(
+			width,
+			height,
+			rows.into_iter().flatten().collect(),
+		))
+	}
+
+	/// Parse one cell per character, where `cell` returns `None` for characters that aren't
+	/// `expected`. Every line must be as long as the first.
+	pub fn parse(
+		input: &str,
+		expected: &str,
+		mut cell: impl FnMut(char) -> Option<T>,
+	) -> Result<Self, ParseError> {
+		let mut width = None;
+		let mut cells = vec![];
+
+		for (y, line) in input.lines().enumerate() {
+			let mut x = 0;
+
+			for (i, c) in line.char_indices() {
+				if width == Some(x) {
+					return Err(ParseError::at(y, line, &line[i..], "end of line"));
+				}
+
+				match cell(c) {
+					Some(value) => cells.push(value),
+					None => return Err(ParseError::new(y, x, expected, c)),
+				}
+
+				x += 1;
+			}
+
+			match width {
+				Some(width) if x < width => {
+					return Err(ParseError::end_of_line(
+						y,
+						line,
+						"a row as long as the first",
+					))
+				}
+				Some(_) => {}
+				None => width = Some(x),
+			}
+		}
+
+		match width {
+			Some(width) if width > 0 => Ok(Self::new(width, cells.len() / width, cells)),
+			_ => Err(ParseError::end_of_input(input, expected)),
+		}
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+		match x < self.width && y < self.height {
+			true => Some(&self.cells[y * self.width + x]),
+			false => None,
+		}
+	}
+
+	pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+		match x < self.width && y < self.height {
+			true => Some(&mut self.cells[y * self.width + x]),
+			false => None,
+		}
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &T> {
+		self.cells.iter()
+	}
+
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+		self.cells.iter_mut()
+	}
+
+	/// Every cell along with its `(x, y)` position, row by row.
+	pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+		let width = self.width;
+
+		self.cells
+			.iter()
+			.enumerate()
+			.map(move |(i, cell)| ((i % width, i / width), cell))
+	}
+
+	/// Position of the first cell, row by row, matching `predicate`.
+	pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+		self.cells
+			.iter()
+			.position(predicate)
+			.map(|i| (i % self.width, i / self.width))
+	}
+
+	pub fn row(&self, y: usize) -> &[T] {
+		&self.cells[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+		&mut self.cells[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+		self.cells.chunks_exact(self.width.max(1))
+	}
+
+	pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+		self.cells.chunks_exact_mut(self.width.max(1))
+	}
+
+	pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+		assert!(x < self.width, "Column to exist");
+
+		self.cells.iter().skip(x).step_by(self.width)
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+		(0..self.width).map(|x| self.column(x))
+	}
+
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+	}
+}
+
+impl<T: Clone> Grid<T> {
+	pub fn filled(width: usize, height: usize, value: T) -> Self {
+		Self::new(width, height, vec![value; width * height])
+	}
+
+	/// Swap rows and columns, so the cell at `(x, y)` moves to `(y, x)`.
+	pub fn transpose(&self) -> Self {
+		let cells = self.columns().flatten().cloned().collect();
+
+		Self::new(self.height, self.width, cells)
+	}
+
+	/// Rotate a quarter turn clockwise, so the left column becomes the top row.
+	pub fn rotate_clockwise(&self) -> Self {
+		let cells = self
+			.columns()
+			.flat_map(|column| column.rev())
+			.cloned()
+			.collect();
+
+		Self::new(self.height, self.width, cells)
+	}
+
+	/// Rotate a quarter turn anticlockwise, so the right column becomes the top row.
+	pub fn rotate_anticlockwise(&self) -> Self {
+		let cells = (0..self.width)
+			.rev()
+			.flat_map(|x| self.column(x))
+			.cloned()
+			.collect();
+
+		Self::new(self.height, self.width, cells)
+	}
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, (x, y): (usize, usize)) -> &T {
+		self.get(x, y).expect("Position to be inside the grid")
+	}
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+	fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+		self.get_mut(x, y).expect("Position to be inside the grid")
+	}
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, point: Point<usize>) -> &T {
+		&self[(point.x, point.y)]
+	}
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+	fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+		&mut self[(point.x, point.y)]
+	}
+}
+
+impl<T: Display> Display for Grid<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		for (y, row) in self.rows().enumerate() {
+			if y > 0 {
+				writeln!(f)?;
+			}
+
+			for cell in row {
+				write!(f, "{cell}")?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests_grid {
+	use super::*;
+
+	fn digits() -> Grid<u32> {
+		Grid::parse("123\n456", "a digit", |c| c.to_digit(10)).unwrap()
+	}
+
+	#[test]
+	fn parse_and_index() {
+		let grid = digits();
+
+		assert_eq!((grid.width(), grid.height()), (3, 2));
+		assert_eq!(grid[(2, 0)], 3);
+		assert_eq!(grid[(0, 1)], 4);
+		assert_eq!(grid.get(3, 0), None);
+		assert_eq!(grid.to_string(), "123\n456");
+	}
+
+	#[test]
+	fn parse_errors() {
+		let parse = |input| Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap_err();
+
+		assert_eq!(
+			parse("123\n4x6").to_string(),
+			"line 2, column 2: expected a digit, found `x`"
+		);
+		assert_eq!(
+			parse("123\n45").to_string(),
+			"line 2, column 3: expected a row as long as the first, found end of line"
+		);
+		assert_eq!(
+			parse("123\n4567").to_string(),
+			"line 2, column 4: expected end of line, found `7`"
+		);
+		assert_eq!(
+			parse("").to_string(),
+			"line 1, column 1: expected a digit, found end of input"
+		);
+	}
+
+	#[test]
+	fn transpose_and_rotate() {
+		let grid = digits();
+
+		assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+		assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+		assert_eq!(grid.rotate_anticlockwise().to_string(), "36\n25\n14");
+		assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid,);
+	}
+
+	#[test]
+	fn rows_and_columns() {
+		let grid = digits();
+
+		assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+		assert_eq!(
+			grid.columns()
+				.map(|column| column.copied().collect::<Vec<_>>())
+				.collect::<Vec<_>>(),
+			[[1, 4], [2, 5], [3, 6]]
+		);
+	}
+}
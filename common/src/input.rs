@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input should be read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+	/// `day_XX/src/part_1_input.txt` inside the workspace.
+	Default,
+	Stdin,
+	Path(PathBuf),
+}
+
+impl InputSource {
+	/// Interpret a command line argument, where `-` means stdin and no argument means the default file.
+	pub fn from_arg(arg: Option<&str>) -> Self {
+		match arg {
+			None => InputSource::Default,
+			Some("-") => InputSource::Stdin,
+			Some(path) => InputSource::Path(PathBuf::from(path)),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct InputError {
+	pub day: u8,
+	pub source: InputSource,
+	pub error: std::io::Error,
+}
+
+impl Display for InputError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let from = match &self.source {
+			InputSource::Default => format!("`{}`", default_path(self.day).display()),
+			InputSource::Stdin => "stdin".to_string(),
+			InputSource::Path(path) => format!("`{}`", path.display()),
+		};
+
+		write!(
+			f,
+			"Could not read input for day {:02} from {from}: {}",
+			self.day, self.error
+		)
+	}
+}
+
+impl std::error::Error for InputError {}
+
+pub fn default_path(day: u8) -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.expect("Workspace root to exist")
+		.join(format!("day_{day:02}"))
+		.join("src")
+		.join("part_1_input.txt")
+}
+
+pub fn load(day: u8, source: &InputSource) -> Result<String, InputError> {
+	let result = match source {
+		InputSource::Default => std::fs::read_to_string(default_path(day)),
+		InputSource::Path(path) => std::fs::read_to_string(path),
+		InputSource::Stdin => {
+			let mut input = String::new();
+			std::io::stdin().read_to_string(&mut input).map(|_| input)
+		}
+	};
+
+	result
+		.map(|input| normalize(&input).into_owned())
+		.map_err(|error| InputError {
+			day,
+			source: source.clone(),
+			error,
+		})
+}
+
+/// The input as the parsers expect it: no byte order mark, `\n` line endings, no whitespace at the end of a line and
+/// no blank lines or newline at the end. Inputs saved on Windows or by an editor that adds a final newline then parse
+/// the same as the original.
+///
+/// Input that is already normal is borrowed rather than copied.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+	let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+	let is_normal = !input.ends_with(char::is_whitespace)
+		&& input.split('\n').all(|line| line.trim_end() == line);
+
+	match is_normal {
+		true => Cow::Borrowed(input),
+		false => Cow::Owned(
+			input
+				.split('\n')
+				.map(str::trim_end)
+				.collect::<Vec<_>>()
+				.join("\n")
+				.trim_end()
+				.to_string(),
+		),
+	}
+}
+
+/// Load the input named by the first command line argument, exiting with a message if it can't be read.
+pub fn from_args(day: u8) -> String {
+	let arg = std::env::args().nth(1);
+	let source = InputSource::from_arg(arg.as_deref());
+
+	crate::error::or_exit(load(day, &source))
+}
+
+#[cfg(test)]
+mod tests_input {
+	use super::*;
+
+	#[test]
+	fn source_from_arg() {
+		assert_eq!(InputSource::from_arg(None), InputSource::Default);
+		assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+		assert_eq!(
+			InputSource::from_arg(Some("input.txt")),
+			InputSource::Path(PathBuf::from("input.txt"))
+		);
+	}
+
+	#[test]
+	fn default_path_points_into_day_crate() {
+		assert!(default_path(5).ends_with("day_05/src/part_1_input.txt"));
+		assert!(load(5, &InputSource::Default).is_ok());
+	}
+
+	#[test]
+	fn normalize_line_endings() {
+		assert_eq!(normalize("a\r\nb\r\n"), "a\nb");
+		assert_eq!(normalize("\u{feff}a\nb"), "a\nb");
+		assert_eq!(normalize("a  \n\t\nb\t\n\n\n"), "a\n\nb");
+		assert_eq!(normalize("1,2,3\n"), "1,2,3");
+		assert_eq!(normalize("\r\n\r\n"), "");
+	}
+
+	#[test]
+	fn normalize_keeps_leading_whitespace() {
+		let input = "\n  #.\n.#";
+
+		assert_eq!(normalize(input), input);
+		assert!(matches!(normalize(input), Cow::Borrowed(_)));
+	}
+
+	#[test]
+	fn missing_file_names_path() {
+		let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+		let error = load(3, &source).expect_err("File to be missing");
+
+		assert_eq!(error.error.kind(), std::io::ErrorKind::NotFound);
+		assert!(error
+			.to_string()
+			.starts_with("Could not read input for day 03 from `does/not/exist.txt`: "));
+	}
+}
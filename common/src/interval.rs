@@ -0,0 +1,316 @@
+/// The integers from `start` up to but not including `end`. It is empty when `end` is not past `start`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Interval {
+	pub start: u64,
+	pub end: u64,
+}
+
+impl Interval {
+	pub fn new(start: u64, end: u64) -> Self {
+		Self { start, end }
+	}
+
+	/// The integers from `first` to `last`, both included.
+	pub fn inclusive(first: u64, last: u64) -> Self {
+		Self::new(first, last + 1)
+	}
+
+	pub fn len(&self) -> u64 {
+		self.end.saturating_sub(self.start)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	pub fn contains(&self, value: u64) -> bool {
+		value >= self.start && value < self.end
+	}
+
+	/// The integers in both intervals, or `None` if they don't overlap.
+	pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+		let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+
+		match overlap.is_empty() {
+			true => None,
+			false => Some(overlap),
+		}
+	}
+
+	/// The integers below `value` and the rest. Either half may be empty.
+	pub fn split_at(&self, value: u64) -> (Interval, Interval) {
+		let value = value.clamp(self.start, self.end.max(self.start));
+
+		(
+			Interval::new(self.start, value),
+			Interval::new(value, self.end),
+		)
+	}
+
+	/// The integers below `other` and those above it, with the overlap removed. Either half may be empty.
+	pub fn subtract(&self, other: &Interval) -> (Interval, Interval) {
+		match other.is_empty() {
+			true => (*self, Interval::new(self.end, self.end)),
+			false => (self.split_at(other.start).0, self.split_at(other.end).1),
+		}
+	}
+}
+
+/// Integers stored as sorted intervals that neither overlap nor touch.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet {
+	intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add every integer in `interval`, merging it with any interval it overlaps or touches.
+	pub fn insert(&mut self, interval: Interval) {
+		if interval.is_empty() {
+			return;
+		}
+
+		let first = self.intervals.partition_point(|i| i.end < interval.start);
+		let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+		let merged = self.intervals[first..last]
+			.iter()
+			.fold(interval, |merged, i| {
+				Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+			});
+
+		self.intervals.splice(first..last, [merged]);
+	}
+
+	pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+		let mut union = self.clone();
+		for interval in other.iter() {
+			union.insert(*interval);
+		}
+
+		union
+	}
+
+	pub fn intersect(&self, other: &IntervalSet) -> IntervalSet {
+		self.iter()
+			.flat_map(|a| other.iter().filter_map(|b| a.intersect(b)))
+			.collect()
+	}
+
+	/// The integers in `self` that aren't in `other`.
+	pub fn subtract(&self, other: &IntervalSet) -> IntervalSet {
+		let mut remaining = self.intervals.clone();
+
+		for removed in other.iter() {
+			remaining = remaining
+				.iter()
+				.flat_map(|interval| {
+					let (below, above) = interval.subtract(removed);
+					[below, above]
+				})
+				.filter(|interval| !interval.is_empty())
+				.collect();
+		}
+
+		remaining.into_iter().collect()
+	}
+
+	/// How many integers are in the set.
+	pub fn len(&self) -> u64 {
+		self.intervals.iter().map(Interval::len).sum()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.intervals.is_empty()
+	}
+
+	pub fn contains(&self, value: u64) -> bool {
+		self.intervals
+			.iter()
+			.any(|interval| interval.contains(value))
+	}
+
+	/// The smallest integer in the set.
+	pub fn min(&self) -> Option<u64> {
+		self.intervals.first().map(|interval| interval.start)
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+		self.intervals.iter()
+	}
+}
+
+impl FromIterator<Interval> for IntervalSet {
+	fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+		let mut set = IntervalSet::new();
+		for interval in iter {
+			set.insert(interval);
+		}
+
+		set
+	}
+}
+
+/// An axis-aligned box of integer points, with an interval for each of its `N` dimensions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Cuboid<const N: usize> {
+	pub axes: [Interval; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+	pub fn new(axes: [Interval; N]) -> Self {
+		Self { axes }
+	}
+
+	/// How many points are in the box.
+	pub fn volume(&self) -> u64 {
+		self.axes.iter().map(Interval::len).product()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.axes.iter().any(Interval::is_empty)
+	}
+
+	pub fn contains(&self, point: [u64; N]) -> bool {
+		self.axes
+			.iter()
+			.zip(point)
+			.all(|(axis, value)| axis.contains(value))
+	}
+
+	/// The points in both boxes, or `None` if they don't overlap.
+	pub fn intersect(&self, other: &Cuboid<N>) -> Option<Cuboid<N>> {
+		let mut axes = self.axes;
+		for (axis, other) in axes.iter_mut().zip(other.axes.iter()) {
+			*axis = axis.intersect(other)?;
+		}
+
+		Some(Self { axes })
+	}
+
+	/// Cut the box across `axis` into the points below `value` and the rest. Either half may be empty.
+	pub fn split_at(&self, axis: usize, value: u64) -> (Cuboid<N>, Cuboid<N>) {
+		let (below, above) = self.axes[axis].split_at(value);
+
+		let mut lower = *self;
+		let mut upper = *self;
+		lower.axes[axis] = below;
+		upper.axes[axis] = above;
+
+		(lower, upper)
+	}
+}
+
+#[cfg(test)]
+mod tests_interval {
+	use super::*;
+
+	fn set(intervals: &[(u64, u64)]) -> IntervalSet {
+		intervals
+			.iter()
+			.map(|(start, end)| Interval::new(*start, *end))
+			.collect()
+	}
+
+	#[test]
+	fn interval_arithmetic() {
+		let interval = Interval::new(10, 20);
+
+		assert_eq!(interval.len(), 10);
+		assert_eq!(Interval::inclusive(1, 4000).len(), 4000);
+		assert!(Interval::new(5, 5).is_empty());
+		assert!(Interval::new(6, 5).is_empty());
+
+		assert_eq!(
+			interval.intersect(&Interval::new(15, 30)),
+			Some(Interval::new(15, 20))
+		);
+		assert_eq!(interval.intersect(&Interval::new(20, 30)), None);
+
+		assert_eq!(
+			interval.split_at(12),
+			(Interval::new(10, 12), Interval::new(12, 20))
+		);
+		assert_eq!(
+			interval.split_at(5),
+			(Interval::new(10, 10), Interval::new(10, 20))
+		);
+		assert_eq!(
+			interval.split_at(25),
+			(Interval::new(10, 20), Interval::new(20, 20))
+		);
+
+		assert_eq!(
+			interval.subtract(&Interval::new(12, 15)),
+			(Interval::new(10, 12), Interval::new(15, 20))
+		);
+		assert_eq!(
+			interval.subtract(&Interval::new(0, 100)),
+			(Interval::new(10, 10), Interval::new(20, 20))
+		);
+	}
+
+	#[test]
+	fn sets_merge_touching_intervals() {
+		let merged = set(&[(10, 20), (30, 40), (20, 25), (50, 50), (0, 5), (35, 45)]);
+
+		assert_eq!(merged, set(&[(0, 5), (10, 25), (30, 45)]));
+		assert_eq!(merged.len(), 5 + 15 + 15);
+		assert_eq!(merged.min(), Some(0));
+		assert!(merged.contains(44) && !merged.contains(45));
+	}
+
+	#[test]
+	fn set_operations() {
+		let a = set(&[(0, 10), (20, 30)]);
+		let b = set(&[(5, 25)]);
+
+		assert_eq!(a.union(&b), set(&[(0, 30)]));
+		assert_eq!(a.intersect(&b), set(&[(5, 10), (20, 25)]));
+		assert_eq!(a.subtract(&b), set(&[(0, 5), (25, 30)]));
+		assert_eq!(b.subtract(&a), set(&[(10, 20)]));
+		assert!(a.subtract(&a).is_empty());
+	}
+
+	#[test]
+	fn set_operations_match_membership() {
+		let a = set(&[(3, 9), (12, 13), (15, 31), (40, 44)]);
+		let b = set(&[(0, 4), (8, 16), (30, 42)]);
+
+		for value in 0..50 {
+			let (in_a, in_b) = (a.contains(value), b.contains(value));
+
+			assert_eq!(a.union(&b).contains(value), in_a || in_b);
+			assert_eq!(a.intersect(&b).contains(value), in_a && in_b);
+			assert_eq!(a.subtract(&b).contains(value), in_a && !in_b);
+		}
+	}
+
+	#[test]
+	fn cuboids() {
+		let cuboid = Cuboid::new([Interval::new(0, 10), Interval::new(0, 4)]);
+
+		assert_eq!(cuboid.volume(), 40);
+		assert!(cuboid.contains([9, 3]) && !cuboid.contains([10, 3]));
+
+		let (left, right) = cuboid.split_at(0, 3);
+		assert_eq!((left.volume(), right.volume()), (12, 28));
+
+		let (empty, all) = cuboid.split_at(1, 0);
+		assert!(empty.is_empty());
+		assert_eq!(all, cuboid);
+
+		let other = Cuboid::new([Interval::new(5, 20), Interval::new(2, 3)]);
+		assert_eq!(
+			cuboid.intersect(&other),
+			Some(Cuboid::new([Interval::new(5, 10), Interval::new(2, 3)]))
+		);
+		assert_eq!(
+			cuboid.intersect(&Cuboid::new([Interval::new(10, 20), Interval::new(0, 4)])),
+			None
+		);
+	}
+}
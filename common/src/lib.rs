@@ -0,0 +1,20 @@
+pub mod animate;
+pub mod cycle;
+pub mod days;
+pub mod differential;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod parse;
+pub mod polygon;
+pub mod render;
+pub mod rng;
+pub mod solution;
+
+pub use error::ParseError;
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use solution::Solution;
@@ -0,0 +1,36 @@
+use log::LevelFilter;
+
+/// The level shown for a number of `-v` flags: warnings by default, then info, debug and trace.
+pub fn level(verbosity: u8) -> LevelFilter {
+	match verbosity {
+		0 => LevelFilter::Warn,
+		1 => LevelFilter::Info,
+		2 => LevelFilter::Debug,
+		_ => LevelFilter::Trace,
+	}
+}
+
+/// Send diagnostics to stderr at the level given by `verbosity`. `RUST_LOG` takes precedence when it is set, with the
+/// usual `env_logger` syntax such as `debug` or `day_13=trace`.
+///
+/// Only the first call has any effect, so it's safe to call from both a binary and its tests.
+pub fn init(verbosity: u8) {
+	let _ = env_logger::Builder::new()
+		.filter_level(level(verbosity))
+		.parse_env("RUST_LOG")
+		.try_init();
+}
+
+#[cfg(test)]
+mod tests_logging {
+	use super::*;
+
+	#[test]
+	fn more_flags_show_more() {
+		assert_eq!(level(0), LevelFilter::Warn);
+		assert_eq!(level(1), LevelFilter::Info);
+		assert_eq!(level(2), LevelFilter::Debug);
+		assert_eq!(level(3), LevelFilter::Trace);
+		assert_eq!(level(u8::MAX), LevelFilter::Trace);
+	}
+}
@@ -0,0 +1,206 @@
+use crate::error::parse_token;
+use crate::ParseError;
+use std::str::FromStr;
+
+/// A run of non-blank lines, with the zero-based index of its first line so errors can point into the whole input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block<'a> {
+	pub start: usize,
+	pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+	/// Each line with its zero-based index in the whole input.
+	pub fn enumerate(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+		self.lines
+			.iter()
+			.enumerate()
+			.map(|(i, line)| (self.start + i, *line))
+	}
+
+	pub fn text(&self) -> String {
+		self.lines.join("\n")
+	}
+}
+
+/// Split the input into blocks separated by one or more blank lines. Lines of only whitespace count as blank.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+	let mut blocks = vec![];
+	let mut current: Option<Block> = None;
+
+	for (y, line) in input.lines().enumerate() {
+		match (line.trim().is_empty(), current.as_mut()) {
+			(true, _) => blocks.extend(current.take()),
+			(false, Some(block)) => block.lines.push(line),
+			(false, None) => {
+				current = Some(Block {
+					start: y,
+					lines: vec![line],
+				})
+			}
+		}
+	}
+
+	blocks.extend(current);
+	blocks
+}
+
+/// Split `slice`, a slice of `line`, around the first `delimiter`, or point at the end of the slice if it is missing.
+pub fn split_once<'a>(
+	line_index: usize,
+	line: &'a str,
+	slice: &'a str,
+	delimiter: &str,
+	expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+	slice
+		.split_once(delimiter)
+		.ok_or_else(|| ParseError::at(line_index, line, &slice[slice.len()..], expected))
+}
+
+/// The label before the first `:` of a line and everything after it, such as `Card 1` and its numbers.
+pub fn labelled<'a>(
+	line_index: usize,
+	line: &'a str,
+	expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+	split_once(line_index, line, line, ":", expected)
+}
+
+/// The whitespace separated numbers in `slice`, a slice of `line`, in any collection.
+pub fn numbers<T: FromStr, C: FromIterator<T>>(
+	line_index: usize,
+	line: &str,
+	slice: &str,
+	expected: &str,
+) -> Result<C, ParseError> {
+	slice
+		.split_whitespace()
+		.map(|token| parse_token(line_index, line, token, expected))
+		.collect()
+}
+
+/// Pairs such as `x=787,m=2655` in `slice`, a slice of `line`, split on `separator` then on `assign`.
+pub fn key_values<'a, T: FromStr>(
+	line_index: usize,
+	line: &'a str,
+	slice: &'a str,
+	separator: char,
+	assign: char,
+	expected: &str,
+) -> Result<Vec<(&'a str, T)>, ParseError> {
+	slice
+		.split(separator)
+		.map(|pair| {
+			let (key, value) = pair.split_once(assign).ok_or_else(|| {
+				ParseError::at(line_index, line, pair, format!("`key{assign}value`"))
+			})?;
+
+			Ok((key, parse_token(line_index, line, value, expected)?))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests_parse {
+	use super::*;
+	use std::collections::HashSet;
+
+	#[test]
+	fn blocks_between_blank_lines() {
+		let input = "a\nb\n\n\nc\n  \nd\ne\n";
+		let blocks = blocks(input);
+
+		assert_eq!(
+			blocks,
+			vec![
+				Block {
+					start: 0,
+					lines: vec!["a", "b"]
+				},
+				Block {
+					start: 4,
+					lines: vec!["c"]
+				},
+				Block {
+					start: 6,
+					lines: vec!["d", "e"]
+				},
+			]
+		);
+		assert_eq!(blocks[2].text(), "d\ne");
+		assert_eq!(
+			blocks[2].enumerate().collect::<Vec<_>>(),
+			vec![(6, "d"), (7, "e")]
+		);
+	}
+
+	#[test]
+	fn blocks_keep_the_last_line() {
+		let blocks = blocks("\nseeds: 1 2\n\nmap:\n1 2 3\n4 5 6");
+
+		assert_eq!(blocks.len(), 2);
+		assert_eq!(blocks[1].lines, vec!["map:", "1 2 3", "4 5 6"]);
+		assert!(super::blocks("").is_empty());
+	}
+
+	#[test]
+	fn labelled_numbers() {
+		let line = "Card 1: 41 48 | 83 86 6";
+		let (label, numbers_part) = labelled(0, line, "`:` after the card").unwrap();
+		let (winning, ours) = split_once(0, line, numbers_part, "|", "`|`").unwrap();
+
+		assert_eq!(label, "Card 1");
+		assert_eq!(
+			numbers::<u32, Vec<_>>(0, line, winning, "a number"),
+			Ok(vec![41, 48])
+		);
+		assert_eq!(
+			numbers::<u32, HashSet<_>>(0, line, ours, "a number"),
+			Ok(HashSet::from([6, 83, 86]))
+		);
+	}
+
+	#[test]
+	fn errors_point_into_the_line() {
+		let line = "Time: 7 1x";
+
+		assert_eq!(
+			labelled(2, "Time 7", "`:` after the label")
+				.unwrap_err()
+				.to_string(),
+			"line 3, column 7: expected `:` after the label, found end of line"
+		);
+		assert_eq!(
+			numbers::<u64, Vec<_>>(0, line, &line[5..], "a number")
+				.unwrap_err()
+				.to_string(),
+			"line 1, column 9: expected a number, found `1x`"
+		);
+		assert_eq!(
+			split_once(0, line, &line[..4], "|", "`|`")
+				.unwrap_err()
+				.to_string(),
+			"line 1, column 5: expected `|`, found nothing"
+		);
+	}
+
+	#[test]
+	fn pairs() {
+		let line = "{x=787,m=2655,a=1222}";
+		let ratings = &line[1..line.len() - 1];
+
+		assert_eq!(
+			key_values::<u64>(0, line, ratings, ',', '=', "a rating"),
+			Ok(vec![("x", 787), ("m", 2655), ("a", 1222)])
+		);
+
+		let line = "{x=787,m2655}";
+		assert_eq!(
+			key_values::<u64>(0, line, &line[1..12], ',', '=', "a rating")
+				.unwrap_err()
+				.to_string(),
+			"line 1, column 8: expected `key=value`, found `m2655`"
+		);
+	}
+}
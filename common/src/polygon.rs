@@ -0,0 +1,171 @@
+use crate::Point;
+
+/// Which way a polygon's vertices wind, as seen on screen where `y` grows to the south.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Orientation {
+	Clockwise,
+	CounterClockwise,
+	/// The polygon encloses no area, e.g. all its vertices lie on one line.
+	Degenerate,
+}
+
+/// Where a point lies relative to a polygon.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Location {
+	Inside,
+	Boundary,
+	Outside,
+}
+
+/// Twice the polygon's area by the shoelace formula, which is positive when the vertices wind clockwise. Doubling
+/// keeps the result whole for any lattice polygon.
+///
+/// The polygon closes itself from the last vertex back to the first, so repeating the first vertex at the end is
+/// optional. The same goes for every function in this module.
+pub fn twice_signed_area(vertices: &[Point<i64>]) -> i64 {
+	edges(vertices).map(|(a, b)| a.x * b.y - a.y * b.x).sum()
+}
+
+pub fn orientation(vertices: &[Point<i64>]) -> Orientation {
+	match twice_signed_area(vertices) {
+		0 => Orientation::Degenerate,
+		area if area > 0 => Orientation::Clockwise,
+		_ => Orientation::CounterClockwise,
+	}
+}
+
+/// How many lattice points lie on the polygon's edges.
+pub fn boundary_points(vertices: &[Point<i64>]) -> i64 {
+	edges(vertices)
+		.map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+		.sum()
+}
+
+/// How many lattice points lie strictly inside the polygon, by Pick's theorem: `A = I + B / 2 - 1`.
+pub fn interior_points(vertices: &[Point<i64>]) -> i64 {
+	(twice_signed_area(vertices).abs() - boundary_points(vertices) + 2) / 2
+}
+
+/// Whether `point` is inside, on or outside the polygon, by counting the edges crossed by a ray cast to the east.
+pub fn locate(vertices: &[Point<i64>], point: Point<i64>) -> Location {
+	let mut inside = false;
+
+	for (a, b) in edges(vertices) {
+		let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+		let within = point.x >= a.x.min(b.x)
+			&& point.x <= a.x.max(b.x)
+			&& point.y >= a.y.min(b.y)
+			&& point.y <= a.y.max(b.y);
+
+		if cross == 0 && within {
+			return Location::Boundary;
+		}
+
+		// Count an edge when it spans the ray's row, treating each vertex as just below the ray so that it isn't
+		// counted twice, and crosses the row east of the point.
+		if (a.y > point.y) != (b.y > point.y) {
+			let east = match b.y > a.y {
+				true => cross > 0,
+				false => cross < 0,
+			};
+
+			inside ^= east;
+		}
+	}
+
+	match inside {
+		true => Location::Inside,
+		false => Location::Outside,
+	}
+}
+
+/// Each edge's start and end, including the edge back to the first vertex.
+fn edges(vertices: &[Point<i64>]) -> impl Iterator<Item = (&Point<i64>, &Point<i64>)> {
+	vertices.iter().zip(vertices.iter().cycle().skip(1))
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+	match b {
+		0 => a,
+		_ => gcd(b, a % b),
+	}
+}
+
+#[cfg(test)]
+mod tests_polygon {
+	use super::*;
+
+	fn polygon(points: &[(i64, i64)]) -> Vec<Point<i64>> {
+		points.iter().map(|(x, y)| Point::new(*x, *y)).collect()
+	}
+
+	#[test]
+	fn square() {
+		let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+
+		assert_eq!(twice_signed_area(&square), 32);
+		assert_eq!(orientation(&square), Orientation::Clockwise);
+		assert_eq!(boundary_points(&square), 16);
+		assert_eq!(interior_points(&square), 9);
+
+		let reversed = square.iter().rev().copied().collect::<Vec<_>>();
+		assert_eq!(twice_signed_area(&reversed), -32);
+		assert_eq!(orientation(&reversed), Orientation::CounterClockwise);
+		assert_eq!(interior_points(&reversed), 9);
+	}
+
+	#[test]
+	fn closing_vertex_is_optional() {
+		let open = polygon(&[(0, 0), (6, 0), (0, 3)]);
+		let closed = polygon(&[(0, 0), (6, 0), (0, 3), (0, 0)]);
+
+		assert_eq!(twice_signed_area(&open), twice_signed_area(&closed));
+		assert_eq!(boundary_points(&open), boundary_points(&closed));
+
+		// Edges of 6, 3 and the diagonal through (2, 2) and (4, 1).
+		assert_eq!(boundary_points(&open), 12);
+		assert_eq!(interior_points(&open), 4);
+	}
+
+	#[test]
+	fn degenerate() {
+		let line = polygon(&[(0, 0), (3, 3), (6, 6)]);
+
+		assert_eq!(orientation(&line), Orientation::Degenerate);
+		assert_eq!(locate(&line, Point::new(2, 2)), Location::Boundary);
+		assert_eq!(locate(&line, Point::new(2, 3)), Location::Outside);
+	}
+
+	#[test]
+	fn locate_matches_pick() {
+		// An L shape with a notch, so rays pass through vertices and along horizontal edges.
+		let shape = polygon(&[
+			(0, 0),
+			(6, 0),
+			(6, 2),
+			(3, 2),
+			(3, 5),
+			(1, 5),
+			(1, 3),
+			(0, 3),
+		]);
+
+		let mut inside = 0;
+		let mut boundary = 0;
+		for y in -1..=6 {
+			for x in -1..=7 {
+				match locate(&shape, Point::new(x, y)) {
+					Location::Inside => inside += 1,
+					Location::Boundary => boundary += 1,
+					Location::Outside => {}
+				}
+			}
+		}
+
+		assert_eq!(inside, interior_points(&shape));
+		assert_eq!(boundary, boundary_points(&shape));
+		assert_eq!(locate(&shape, Point::new(2, 1)), Location::Inside);
+		assert_eq!(locate(&shape, Point::new(4, 3)), Location::Outside);
+		assert_eq!(locate(&shape, Point::new(3, 4)), Location::Boundary);
+	}
+}
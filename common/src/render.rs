@@ -0,0 +1,275 @@
+use crate::{Grid, Point};
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Colour {
+	pub r: u8,
+	pub g: u8,
+	pub b: u8,
+}
+
+impl Colour {
+	pub const BLACK: Colour = Colour::rgb(0, 0, 0);
+	pub const WHITE: Colour = Colour::rgb(255, 255, 255);
+
+	pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+		Self { r, g, b }
+	}
+
+	/// Blend from `self` to `other`, where `t` runs from `0.0` to `1.0`.
+	pub fn mix(self, other: Colour, t: f64) -> Self {
+		let channel =
+			|a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t.clamp(0.0, 1.0)).round() as u8;
+
+		Self::rgb(
+			channel(self.r, other.r),
+			channel(self.g, other.g),
+			channel(self.b, other.b),
+		)
+	}
+
+	/// The colour as an SVG `#rrggbb` string.
+	pub fn hex(&self) -> String {
+		format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+	}
+}
+
+/// A binary PPM (`P6`) image with every cell drawn as a `scale` by `scale` square.
+pub fn grid_ppm(grid: &Grid<Colour>, scale: usize) -> Vec<u8> {
+	let scale = scale.max(1);
+	let mut image = format!(
+		"P6\n{} {}\n255\n",
+		grid.width() * scale,
+		grid.height() * scale
+	)
+	.into_bytes();
+
+	for row in grid.rows() {
+		let line = row
+			.iter()
+			.flat_map(|colour| [colour.r, colour.g, colour.b].repeat(scale))
+			.collect::<Vec<_>>();
+
+		for _ in 0..scale {
+			image.extend_from_slice(&line);
+		}
+	}
+
+	image
+}
+
+/// An SVG image with every cell drawn as a `scale` by `scale` square. Runs of the same colour along a row are merged.
+pub fn grid_svg(grid: &Grid<Colour>, scale: usize) -> String {
+	let scale = scale.max(1);
+	let mut svg = svg_header(grid.width() * scale, grid.height() * scale);
+
+	for (y, row) in grid.rows().enumerate() {
+		let mut x = 0;
+
+		for run in row.chunk_by(|a, b| a == b) {
+			writeln!(
+				svg,
+				r#"<rect x="{}" y="{}" width="{}" height="{scale}" fill="{}"/>"#,
+				x * scale,
+				y * scale,
+				run.len() * scale,
+				run[0].hex()
+			)
+			.expect("Writing to a string to succeed");
+
+			x += run.len();
+		}
+	}
+
+	svg.push_str("</svg>\n");
+	svg
+}
+
+/// A closed polygon through the centres of the cells at `vertices`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polygon {
+	pub vertices: Vec<Point<i64>>,
+	pub fill: Colour,
+	pub stroke: Colour,
+}
+
+impl Polygon {
+	/// An SVG image, scaled so the longest side is `size` pixels.
+	pub fn svg(&self, size: usize) -> String {
+		let bounds = Bounds::new(&self.vertices, size);
+		let mut svg = svg_header(bounds.width, bounds.height);
+
+		let points = self
+			.vertices
+			.iter()
+			.map(|point| {
+				let (x, y) = bounds.project(*point);
+				format!("{x:.2},{y:.2}")
+			})
+			.collect::<Vec<_>>()
+			.join(" ");
+
+		writeln!(
+			svg,
+			r#"<polygon points="{points}" fill="{}" stroke="{}" stroke-width="1"/>"#,
+			self.fill.hex(),
+			self.stroke.hex()
+		)
+		.expect("Writing to a string to succeed");
+
+		svg.push_str("</svg>\n");
+		svg
+	}
+
+	/// Rasterise onto a grid whose longest side is `size` pixels. The inside is found with the even-odd rule and the
+	/// edges are drawn on top, so cells along the boundary always take the stroke colour.
+	pub fn rasterise(&self, size: usize, background: Colour) -> Grid<Colour> {
+		let bounds = Bounds::new(&self.vertices, size);
+		let mut grid = Grid::filled(bounds.width, bounds.height, background);
+
+		let edges = self
+			.vertices
+			.iter()
+			.zip(self.vertices.iter().cycle().skip(1))
+			.map(|(a, b)| (bounds.project(*a), bounds.project(*b)))
+			.collect::<Vec<_>>();
+
+		for y in 0..bounds.height {
+			// Sample through the middle of each row of pixels.
+			let sample = y as f64 + 0.5;
+
+			let mut crossings = edges
+				.iter()
+				.filter(|((_, y1), (_, y2))| (*y1 <= sample) != (*y2 <= sample))
+				.map(|((x1, y1), (x2, y2))| x1 + (sample - y1) / (y2 - y1) * (x2 - x1))
+				.collect::<Vec<_>>();
+			crossings.sort_by(f64::total_cmp);
+
+			for span in crossings.chunks_exact(2) {
+				let start = (span[0] - 0.5).ceil().max(0.0) as usize;
+				let end = ((span[1] - 0.5).floor() as usize).min(bounds.width - 1);
+
+				for cell in &mut grid.row_mut(y)[start..=end.max(start)] {
+					*cell = self.fill;
+				}
+			}
+		}
+
+		for ((x1, y1), (x2, y2)) in edges {
+			let steps = (x2 - x1).abs().max((y2 - y1).abs()).ceil().max(1.0) as usize;
+
+			for i in 0..=steps {
+				let t = i as f64 / steps as f64;
+				let x = ((x1 + (x2 - x1) * t) as usize).min(bounds.width - 1);
+				let y = ((y1 + (y2 - y1) * t) as usize).min(bounds.height - 1);
+
+				grid[(x, y)] = self.stroke;
+			}
+		}
+
+		grid
+	}
+}
+
+fn svg_header(width: usize, height: usize) -> String {
+	format!(
+		"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+	)
+}
+
+/// Maps polygon coordinates onto an image whose longest side is a given number of pixels.
+struct Bounds {
+	min: Point<i64>,
+	scale: f64,
+	width: usize,
+	height: usize,
+}
+
+impl Bounds {
+	fn new(vertices: &[Point<i64>], size: usize) -> Self {
+		let min_x = vertices.iter().map(|p| p.x).min().unwrap_or(0);
+		let max_x = vertices.iter().map(|p| p.x).max().unwrap_or(0);
+		let min_y = vertices.iter().map(|p| p.y).min().unwrap_or(0);
+		let max_y = vertices.iter().map(|p| p.y).max().unwrap_or(0);
+
+		// Treat each coordinate as a whole cell, so a polygon is one unit wider than its span.
+		let span_x = (max_x - min_x + 1) as f64;
+		let span_y = (max_y - min_y + 1) as f64;
+		let scale = size.max(1) as f64 / span_x.max(span_y);
+
+		Self {
+			min: Point::new(min_x, min_y),
+			scale,
+			width: ((span_x * scale).round() as usize).max(1),
+			height: ((span_y * scale).round() as usize).max(1),
+		}
+	}
+
+	/// The centre of the cell at `point`, in pixels.
+	fn project(&self, point: Point<i64>) -> (f64, f64) {
+		(
+			((point.x - self.min.x) as f64 + 0.5) * self.scale,
+			((point.y - self.min.y) as f64 + 0.5) * self.scale,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests_render {
+	use super::*;
+
+	const RED: Colour = Colour::rgb(255, 0, 0);
+
+	#[test]
+	fn ppm_scales_cells() {
+		let grid = Grid::new(2, 1, vec![RED, Colour::WHITE]);
+		let image = grid_ppm(&grid, 2);
+		let header = b"P6\n4 2\n255\n";
+
+		assert_eq!(&image[..header.len()], header);
+		assert_eq!(
+			&image[header.len()..],
+			[[255, 0, 0].repeat(2), vec![255; 6]].concat().repeat(2)
+		);
+	}
+
+	#[test]
+	fn svg_merges_runs() {
+		let grid = Grid::new(3, 1, vec![RED, RED, Colour::BLACK]);
+		let svg = grid_svg(&grid, 10);
+
+		assert!(svg.contains(r##"<rect x="0" y="0" width="20" height="10" fill="#ff0000"/>"##));
+		assert!(svg.contains(r##"<rect x="20" y="0" width="10" height="10" fill="#000000"/>"##));
+	}
+
+	#[test]
+	fn polygon_fills_inside() {
+		let polygon = |vertices: &[Point<i64>]| Polygon {
+			vertices: vertices.to_vec(),
+			fill: RED,
+			stroke: Colour::WHITE,
+		};
+
+		let square = polygon(&[
+			Point::new(0, 0),
+			Point::new(9, 0),
+			Point::new(9, 9),
+			Point::new(0, 9),
+		]);
+		let grid = square.rasterise(10, Colour::BLACK);
+
+		assert_eq!((grid.width(), grid.height()), (10, 10));
+		assert_eq!(grid[(0, 0)], Colour::WHITE);
+		assert_eq!(grid[(9, 9)], Colour::WHITE);
+		assert_eq!(grid[(4, 5)], RED);
+		assert!(square
+			.svg(100)
+			.contains(r##"points="5.00,5.00 95.00,5.00 95.00,95.00 5.00,95.00""##));
+
+		let triangle = polygon(&[Point::new(0, 0), Point::new(9, 0), Point::new(0, 9)]);
+		let grid = triangle.rasterise(10, Colour::BLACK);
+
+		assert_eq!(grid[(2, 2)], RED);
+		assert_eq!(grid[(9, 9)], Colour::BLACK);
+	}
+}
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+use std::ops::{Range, RangeInclusive};
+
+/// A small seedable pseudo-random generator (SplitMix64), so generated inputs can be reproduced from their seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+	state: u64,
+}
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Self { state: seed }
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// A number in `range`, which must not be empty.
+	pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+		let (start, end) = range.into_inner();
+		assert!(start <= end, "Range to not be empty");
+
+		match end - start {
+			u64::MAX => self.next_u64(),
+			span => start + ((self.next_u64() as u128 * (span as u128 + 1)) >> 64) as u64,
+		}
+	}
+
+	/// An index into a collection of `len` items, which must not be empty.
+	pub fn index(&mut self, len: usize) -> usize {
+		assert!(len > 0, "Collection to not be empty");
+
+		self.range(0..=len as u64 - 1) as usize
+	}
+
+	/// `true` with the given probability.
+	pub fn chance(&mut self, probability: f64) -> bool {
+		((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+	}
+
+	pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.index(items.len())]
+	}
+
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.index(i + 1));
+		}
+	}
+}
+
+/// Each input `generate` makes at `size` from the given seeds, parsed with `parse`, for tests over many random inputs.
+/// Panics with the seed of any input that doesn't parse, so `aoc gen --seed` can reproduce it.
+pub fn generated<T, E: Display>(
+	generate: fn(&mut Rng, usize) -> String,
+	size: usize,
+	seeds: Range<u64>,
+	parse: impl Fn(&str) -> Result<T, E>,
+) -> impl Iterator<Item = T> {
+	seeds.map(move |seed| {
+		let input = generate(&mut Rng::new(seed), size);
+
+		parse(&input).unwrap_or_else(|e| panic!("Input generated from seed {seed} to parse: {e}"))
+	})
+}
+
+#[cfg(test)]
+mod tests_rng {
+	use super::*;
+
+	#[test]
+	fn splitmix_sequence() {
+		let mut rng = Rng::new(0);
+
+		assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+		assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+		assert_eq!(rng.next_u64(), 0x06c4_5d18_8009_454f);
+	}
+
+	#[test]
+	fn ranges_stay_in_bounds() {
+		let mut rng = Rng::new(42);
+
+		for _ in 0..1000 {
+			assert!((3..=7).contains(&rng.range(3..=7)));
+			assert!(rng.index(5) < 5);
+		}
+
+		assert_eq!(rng.range(9..=9), 9);
+		assert!((0..1000).any(|_| rng.range(0..=1) == 1));
+	}
+
+	#[test]
+	fn same_seed_same_values() {
+		let mut a = Rng::new(7);
+		let mut b = Rng::new(7);
+		let mut items = (0..10).collect::<Vec<_>>();
+		a.shuffle(&mut items);
+
+		let mut expected = (0..10).collect::<Vec<_>>();
+		b.shuffle(&mut expected);
+
+		assert_eq!(items, expected);
+		assert_eq!(a.next_u64(), b.next_u64());
+	}
+}
@@ -0,0 +1,99 @@
+use crate::error::{NoAnswer, SolveError};
+use crate::input::normalize;
+use crate::ParseError;
+
+/// A day's puzzle: a parse stage shared by both parts, followed by the two solvers.
+pub trait Solution {
+	type Input;
+	type Part1: Answer;
+	type Part2: Answer;
+	/// Numbers the puzzle text fixes, such as how many times to repeat a step, which the runner reads from `aoc.toml`.
+	/// Its default is the puzzle as set. Days without any use `()`.
+	type Params: Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+	fn part_1(input: &Self::Input) -> Self::Part1;
+
+	fn part_2(input: &Self::Input) -> Self::Part2;
+
+	/// Solve part 1 with the given parameters. Days with parameters override this, and solve with the defaults in
+	/// `part_1`.
+	fn part_1_with(input: &Self::Input, _params: &Self::Params) -> Self::Part1 {
+		Self::part_1(input)
+	}
+
+	/// Solve part 2 with the given parameters. Days with parameters override this, and solve with the defaults in
+	/// `part_2`.
+	fn part_2_with(input: &Self::Input, _params: &Self::Params) -> Self::Part2 {
+		Self::part_2(input)
+	}
+}
+
+/// A day's puzzle parameters.
+pub trait Params: Default {
+	/// Whether there are no parameters at all, so any given for the day are a mistake. Only `()` has none.
+	fn is_empty() -> bool {
+		false
+	}
+
+	/// Check for values no input can be solved with, before solving anything.
+	fn validate(&self) -> Result<(), String> {
+		Ok(())
+	}
+}
+
+impl Params for () {
+	fn is_empty() -> bool {
+		true
+	}
+}
+
+/// What a part gives back. Parts which can find that an input has no answer, even though it parsed, return a
+/// `Result` holding the answer.
+pub trait Answer {
+	/// The answer as text, or why there is none.
+	fn into_answer(self) -> Result<String, NoAnswer>;
+
+	/// The Rust type of the answer itself.
+	fn type_name() -> &'static str {
+		std::any::type_name::<Self>()
+	}
+}
+
+macro_rules! answer {
+	($($t:ty),*) => {
+		$(
+			impl Answer for $t {
+				fn into_answer(self) -> Result<String, NoAnswer> {
+					Ok(self.to_string())
+				}
+			}
+		)*
+	};
+}
+
+answer!(i64, u32, u64, usize, String);
+
+impl<T: Answer> Answer for Result<T, NoAnswer> {
+	fn into_answer(self) -> Result<String, NoAnswer> {
+		self?.into_answer()
+	}
+
+	fn type_name() -> &'static str {
+		T::type_name()
+	}
+}
+
+/// Normalize and parse `input`, then solve `part` with the given parameters, giving the answer as text.
+pub fn solve<S: Solution>(part: u8, input: &str, params: &S::Params) -> Result<String, SolveError> {
+	let input = S::parse(&normalize(input))?;
+
+	let answer = match part {
+		1 => S::part_1_with(&input, params).into_answer()?,
+		2 => S::part_2_with(&input, params).into_answer()?,
+		_ => unreachable!("Only parts 1 and 2 exist"),
+	};
+
+	Ok(answer)
+}
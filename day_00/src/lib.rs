@@ -0,0 +1,62 @@
+use common::rng::Rng;
+use common::{ParseError, Solution};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+	type Input = Vec<String>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(lines: &Vec<String>) -> u64 {
+		part_1(lines)
+	}
+
+	fn part_2(lines: &Vec<String>) -> u64 {
+		part_2(lines)
+	}
+}
+
+pub fn part_1(_lines: &[String]) -> u64 {
+	1
+}
+
+pub fn part_2(_lines: &[String]) -> u64 {
+	1
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+	Ok(input.lines().map(|line| line.to_string()).collect())
+}
+
+/// An input of `size` lines of digits, until the day generates inputs shaped like its puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| rng.range(0..=999).to_string())
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[cfg(test)]
+mod tests_day_00 {
+	use super::*;
+
+	#[test]
+	fn part_01_example() {
+		let input = include_str!("example.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 1);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = include_str!("example.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 1);
+	}
+}
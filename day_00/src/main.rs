@@ -1,39 +1,11 @@
-fn main() {
-	let input = include_str!("part_1_input.txt");
-
-	dbg!(part_1(input));
-	dbg!(part_2(input));
-}
-
-fn part_1(_input: &str) -> u64 {
-	1
-}
-
-fn part_2(_input: &str) -> u64 {
-	1
-}
-
-#[cfg(test)]
-mod tests_day_00 {
-	use super::*;
-
-	#[test]
-	fn part_01_example() {
-		let input = r#"
+const DAY: u8 = 0;
 
-"#
-		.trim();
-
-		assert_eq!(part_1(input), 1);
-	}
-
-	#[test]
-	fn part_02_example() {
-		let input = r#"
+fn main() {
+	common::logging::init(0);
 
-"#
-		.trim();
+	let input = common::input::from_args(DAY);
+	let input = common::error::or_exit(day_00::parse(&input));
 
-		assert_eq!(part_2(input), 1);
-	}
+	println!("Day {DAY:02}, part 1: {}", day_00::part_1(&input));
+	println!("Day {DAY:02}, part 2: {}", day_00::part_2(&input));
 }
@@ -0,0 +1,160 @@
+use common::rng::Rng;
+use common::{ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+	type Input = Vec<String>;
+	type Part1 = u32;
+	type Part2 = u32;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(lines: &Vec<String>) -> u32 {
+		part_1(lines)
+	}
+
+	fn part_2(lines: &Vec<String>) -> u32 {
+		part_2(lines)
+	}
+}
+
+pub fn part_1(lines: &[String]) -> u32 {
+	lines
+		.iter()
+		.map(|line| {
+			let numbers: Vec<_> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+
+			let first = *numbers.first().expect("Expect one number to always exist");
+			let last = *numbers.last().expect("Expect one number to always exist");
+
+			(10 * first) + last
+		})
+		.sum()
+}
+
+pub fn part_2(lines: &[String]) -> u32 {
+	lines
+		.iter()
+		.map(|line| {
+			let mut digits = vec![];
+
+			for (i, char) in line.char_indices() {
+				let digit = match char.to_digit(10) {
+					Some(d) => Some(d),
+					None => {
+						let sub_line = &line[i..];
+
+						if sub_line.starts_with("one") {
+							Some(1)
+						} else if sub_line.starts_with("two") {
+							Some(2)
+						} else if sub_line.starts_with("three") {
+							Some(3)
+						} else if sub_line.starts_with("four") {
+							Some(4)
+						} else if sub_line.starts_with("five") {
+							Some(5)
+						} else if sub_line.starts_with("six") {
+							Some(6)
+						} else if sub_line.starts_with("seven") {
+							Some(7)
+						} else if sub_line.starts_with("eight") {
+							Some(8)
+						} else if sub_line.starts_with("nine") {
+							Some(9)
+						} else {
+							None
+						}
+					}
+				};
+
+				if let Some(digit) = digit {
+					digits.push(digit);
+				}
+			}
+
+			let first = *digits.first().expect("Expect one number to always exist");
+			let last = *digits.last().expect("Expect one number to always exist");
+
+			(10 * first) + last
+		})
+		.sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+	Ok(input.lines().map(|line| line.to_string()).collect())
+}
+
+/// A calibration document of `size` lines mixing letters, digits and spelled out digits. Every line has at least one
+/// numeric digit, as part 1 needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	const WORDS: [&str; 9] = [
+		"one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+	];
+
+	(0..size)
+		.map(|_| {
+			let tokens = rng.range(2..=6) as usize;
+			let digit_at = rng.index(tokens);
+
+			(0..tokens)
+				.map(|i| match (i == digit_at, rng.index(3)) {
+					(true, _) | (false, 0) => rng.range(1..=9).to_string(),
+					(false, 1) => rng.choose(&WORDS).to_string(),
+					_ => (0..rng.range(1..=4))
+						.map(|_| (b'a' + rng.index(26) as u8) as char)
+						.collect(),
+				})
+				.collect::<String>()
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[cfg(test)]
+mod tests_day_01 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 142);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 281);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for lines in generated(generate, 50, 0..20, parse) {
+			assert_eq!(lines.len(), 50);
+			assert!(part_1(&lines) >= 50 * 11);
+			assert!(part_2(&lines) >= 50 * 11);
+		}
+	}
+}
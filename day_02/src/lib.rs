@@ -0,0 +1,273 @@
+use common::error::parse_token;
+use common::parse;
+use common::rng::Rng;
+use common::solution;
+use common::{ParseError, Solution};
+use serde::Deserialize;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+	type Input = Vec<Game>;
+	type Part1 = u32;
+	type Part2 = u32;
+	type Params = Params;
+
+	fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(games: &Vec<Game>) -> u32 {
+		part_1(games)
+	}
+
+	fn part_2(games: &Vec<Game>) -> u32 {
+		part_2(games)
+	}
+
+	fn part_1_with(games: &Vec<Game>, params: &Params) -> u32 {
+		part_1_with(games, params)
+	}
+}
+
+/// How many cubes of each colour are in the bag for part 1.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	pub red: u32,
+	pub green: u32,
+	pub blue: u32,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			red: 12,
+			green: 13,
+			blue: 14,
+		}
+	}
+}
+
+impl solution::Params for Params {}
+
+pub fn part_1(games: &[Game]) -> u32 {
+	part_1_with(games, &Params::default())
+}
+
+/// The sum of the numbers of the games that are possible with the given bag.
+pub fn part_1_with(games: &[Game], bag: &Params) -> u32 {
+	games
+		.iter()
+		.map(|game| {
+			let game_valid = game.rounds.iter().all(|round| {
+				round.red <= bag.red && round.green <= bag.green && round.blue <= bag.blue
+			});
+
+			match game_valid {
+				true => game.number,
+				false => 0,
+			}
+		})
+		.sum()
+}
+
+pub fn part_2(games: &[Game]) -> u32 {
+	games
+		.iter()
+		.map(|game| {
+			let (r_max, g_max, b_max) = game
+				.rounds
+				.iter()
+				.map(|round| (round.red, round.green, round.blue))
+				.reduce(|l, r| (l.0.max(r.0), l.1.max(r.1), l.2.max(r.2)))
+				.expect("Iterator will not be empty");
+
+			r_max * g_max * b_max
+		})
+		.sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let (game, rounds) = parse::labelled(y, line, "`:` after the game number")?;
+
+			let number = extract_game_number(y, line, game)?;
+
+			let rounds = rounds
+				.split(';')
+				.map(|round| {
+					let mut red = 0;
+					let mut green = 0;
+					let mut blue = 0;
+
+					let pulls = round.split(',');
+					for pull in pulls {
+						let (num, colour) = get_pull_num_and_colour(y, line, pull)?;
+
+						match colour {
+							"red" => red = red.max(num),
+							"green" => green = green.max(num),
+							"blue" => blue = blue.max(num),
+							_ => {
+								return Err(ParseError::at(
+									y,
+									line,
+									colour,
+									"`red`, `green` or `blue`",
+								))
+							}
+						}
+					}
+
+					Ok(Round { red, green, blue })
+				})
+				.collect::<Result<_, _>>()?;
+
+			Ok(Game { number, rounds })
+		})
+		.collect()
+}
+
+/// A record of `size` games, each with up to six rounds of coloured cubes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(1..=size)
+		.map(|game| {
+			let rounds = (0..rng.range(1..=6))
+				.map(|_| {
+					let mut colours = ["red", "green", "blue"];
+					rng.shuffle(&mut colours);
+
+					colours[..rng.range(1..=3) as usize]
+						.iter()
+						.map(|colour| format!("{} {colour}", rng.range(1..=20)))
+						.collect::<Vec<_>>()
+						.join(", ")
+				})
+				.collect::<Vec<_>>()
+				.join("; ");
+
+			format!("Game {game}: {rounds}")
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+fn extract_game_number(y: usize, line: &str, game: &str) -> Result<u32, ParseError> {
+	let num = game
+		.split_ascii_whitespace()
+		.last()
+		.ok_or_else(|| ParseError::at(y, line, game, "a game number"))?;
+
+	parse_token(y, line, num, "a game number")
+}
+
+fn get_pull_num_and_colour<'a>(
+	y: usize,
+	line: &'a str,
+	pull: &'a str,
+) -> Result<(u32, &'a str), ParseError> {
+	let mut pull_split = pull.split_ascii_whitespace();
+	let num = pull_split
+		.next()
+		.ok_or_else(|| ParseError::at(y, line, pull, "a number of cubes"))?;
+	let num = parse_token(y, line, num, "a number of cubes")?;
+
+	let colour = pull_split
+		.next()
+		.ok_or_else(|| ParseError::at(y, line, &pull[pull.len()..], "a colour"))?;
+
+	Ok((num, colour))
+}
+
+#[derive(Debug)]
+pub struct Game {
+	pub number: u32,
+	pub rounds: Vec<Round>,
+}
+
+#[derive(Debug)]
+pub struct Round {
+	pub red: u32,
+	pub green: u32,
+	pub blue: u32,
+}
+
+#[cfg(test)]
+mod tests_day_02 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 8);
+	}
+
+	#[test]
+	fn part_01_bigger_bag() {
+		let input = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#
+		.trim();
+		let bag = Params {
+			red: 20,
+			blue: 15,
+			..Params::default()
+		};
+
+		assert_eq!(part_1_with(&parse(input).unwrap(), &bag), 15);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 2286);
+	}
+
+	#[test]
+	fn parse_error_location() {
+		let input = r#"
+Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 grene
+"#
+		.trim();
+
+		assert_eq!(
+			parse(input).unwrap_err().to_string(),
+			"line 2, column 19: expected `red`, `green` or `blue`, found `grene`"
+		);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for games in generated(generate, 50, 0..20, parse) {
+			assert_eq!(games.len(), 50);
+			assert!(part_1(&games) <= (1..=50).sum());
+			assert!(part_2(&games) > 0);
+		}
+	}
+}
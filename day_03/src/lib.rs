@@ -0,0 +1,247 @@
+use common::error::parse_token;
+use common::rng::Rng;
+use common::{Grid, ParseError, Point, Solution};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+	type Input = Engine;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Engine, ParseError> {
+		parse_input(input)
+	}
+
+	fn part_1(engine: &Engine) -> u64 {
+		part_1(engine)
+	}
+
+	fn part_2(engine: &Engine) -> u64 {
+		part_2(engine)
+	}
+}
+
+pub fn part_1(engine: &Engine) -> u64 {
+	engine
+		.numbers
+		.iter()
+		.map(|n| {
+			let adjacent_coordinates = n.generate_adjacent_coordinates();
+			let next_to_symbol = adjacent_coordinates
+				.iter()
+				.any(|c| engine.symbols.contains(c));
+
+			match next_to_symbol {
+				true => n.value,
+				false => 0,
+			}
+		})
+		.sum()
+}
+
+pub fn part_2(engine: &Engine) -> u64 {
+	engine
+		.gears
+		.iter()
+		.map(|gear| {
+			let adjacent = gear
+				.neighbours_8()
+				.flat_map(|c| {
+					let number = engine.numbers_by_coord.get(&c).map(|n| (n.id, n.value));
+
+					number
+				})
+				.collect::<HashSet<(u64, u64)>>();
+
+			let unique = adjacent.iter().cloned().collect::<HashSet<(u64, u64)>>();
+
+			let has_two_unique = unique.len() == 2;
+
+			let adjacent: Vec<_> = unique.into_iter().collect();
+
+			match has_two_unique {
+				true => adjacent[0].1 * adjacent[1].1,
+				false => 0,
+			}
+		})
+		.sum()
+}
+
+pub fn parse_input(input: &str) -> Result<Engine, ParseError> {
+	let mut numbers = vec![];
+	let mut numbers_by_coord = HashMap::new();
+	let mut symbols = HashSet::new();
+	let mut gears = vec![];
+
+	let mut id = 0;
+
+	let grid = Grid::parse(input, "a digit, `.` or a symbol", |c| {
+		c.is_ascii_graphic().then_some(c)
+	})?;
+
+	for (y, row) in grid.rows().enumerate() {
+		let line = row.iter().chain(['.'].iter()).collect::<String>();
+		let mut parsing_number = false;
+		let mut number_start = 0;
+
+		for (x, char) in line.chars().enumerate() {
+			if char.is_ascii_digit() && !parsing_number {
+				number_start = x;
+				parsing_number = true;
+			} else if !char.is_ascii_digit() && parsing_number {
+				let number_end = x - 1;
+				parsing_number = false;
+
+				let num = &line[number_start..=number_end];
+				let num = parse_token(y, &line, num, "a part number")?;
+
+				id += 1;
+				let number = Rc::new(Number {
+					id,
+					value: num,
+					coordinates: (number_start..=number_end)
+						.map(|x| Point::new(x, y))
+						.collect(),
+				});
+
+				numbers.push(number.clone());
+				for coordinate in number.coordinates.iter() {
+					numbers_by_coord.insert(*coordinate, number.clone());
+				}
+			}
+
+			if !char.is_ascii_digit() && char != '.' {
+				symbols.insert(Point::new(x, y));
+
+				if char == '*' {
+					gears.push(Point::new(x, y));
+				}
+			}
+		}
+	}
+
+	Ok(Engine {
+		numbers,
+		numbers_by_coord,
+		symbols,
+		gears,
+	})
+}
+
+/// A `size` by `size` engine schematic of part numbers scattered between symbols.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+	let size = size.max(1);
+
+	(0..size)
+		.map(|_| {
+			let mut line = String::new();
+
+			while line.len() < size {
+				let roll = rng.index(10);
+
+				match roll {
+					0..=1 => {
+						let digits = (rng.range(1..=3) as usize).min(size - line.len());
+						line.push_str(&rng.range(1..=9).to_string());
+						(1..digits).for_each(|_| line.push_str(&rng.range(0..=9).to_string()));
+
+						if line.len() < size {
+							line.push('.');
+						}
+					}
+					2 => line.push(*rng.choose(&SYMBOLS)),
+					_ => line.push('.'),
+				}
+			}
+
+			line
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+pub struct Engine {
+	pub numbers: Vec<Rc<Number>>,
+	pub numbers_by_coord: HashMap<Point<usize>, Rc<Number>>,
+	pub symbols: HashSet<Point<usize>>,
+	pub gears: Vec<Point<usize>>,
+}
+
+#[derive(Debug)]
+pub struct Number {
+	pub id: u64,
+	pub value: u64,
+	pub coordinates: Vec<Point<usize>>,
+}
+
+impl Number {
+	/// Every point touching the number, diagonals included.
+	pub fn generate_adjacent_coordinates(&self) -> Vec<Point<usize>> {
+		self.coordinates
+			.iter()
+			.flat_map(|c| c.neighbours_8())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests_day_03 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse_input(input).unwrap()), 4361);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+    "#
+		.trim();
+
+		assert_eq!(part_2(&parse_input(input).unwrap()), 467835);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		let parse = |input: &str| {
+			assert!(input.lines().all(|line| line.len() == 40));
+			parse_input(input)
+		};
+
+		for engine in generated(generate, 40, 0..20, parse) {
+			part_1(&engine);
+			part_2(&engine);
+		}
+	}
+}
@@ -0,0 +1,160 @@
+use common::parse;
+use common::rng::Rng;
+use common::{ParseError, Solution};
+use std::collections::HashSet;
+use std::rc::Rc;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+	type Input = Vec<Rc<Card>>;
+	type Part1 = u32;
+	type Part2 = u32;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<Rc<Card>>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(cards: &Vec<Rc<Card>>) -> u32 {
+		part_1(cards)
+	}
+
+	fn part_2(cards: &Vec<Rc<Card>>) -> u32 {
+		part_2(cards)
+	}
+}
+
+pub fn part_1(cards: &[Rc<Card>]) -> u32 {
+	cards
+		.iter()
+		.map(|card| {
+			let base: u32 = 2;
+			let matches = card.num_matches() as u32;
+
+			match matches == 0 {
+				true => 0,
+				false => base.pow(matches - 1),
+			}
+		})
+		.sum()
+}
+
+pub fn part_2(cards: &[Rc<Card>]) -> u32 {
+	let mut won_cards = vec![0_u32; cards.len()];
+
+	cards.iter().enumerate().for_each(|(i, card)| {
+		won_cards[i] += 1;
+
+		let multiplier = won_cards[i];
+		for j in 1..=card.num_matches() {
+			won_cards[i + j] += multiplier;
+		}
+	});
+
+	won_cards.iter().sum()
+}
+
+pub struct Card {
+	pub winning_numbers: HashSet<u32>,
+	pub our_numbers: HashSet<u32>,
+}
+
+impl Card {
+	/// How many of our numbers are winning numbers.
+	pub fn num_matches(&self) -> usize {
+		self.winning_numbers.intersection(&self.our_numbers).count()
+	}
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rc<Card>>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let (_, numbers) = parse::labelled(y, line, "`:` after the card number")?;
+			let (winning, ours) =
+				parse::split_once(y, line, numbers, "|", "`|` between the numbers")?;
+
+			let winning = parse::numbers(y, line, winning, "a number")?;
+			let ours = parse::numbers(y, line, ours, "a number")?;
+
+			Ok(Rc::new(Card {
+				winning_numbers: winning,
+				our_numbers: ours,
+			}))
+		})
+		.collect()
+}
+
+/// `size` scratchcards, each with five winning numbers and eight of our own. Matches are kept rare so the number of
+/// copies won in part 2 stays small, and no card wins copies past the end of the table.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(1..=size)
+		.map(|card| {
+			let mut numbers = (1..=99).collect::<Vec<u64>>();
+			rng.shuffle(&mut numbers);
+
+			let matches = (0..5).filter(|_| rng.chance(0.15)).count().min(size - card);
+			let winning = &numbers[..5];
+			let mut ours = [&winning[..matches], &numbers[5..13 - matches]].concat();
+			rng.shuffle(&mut ours);
+
+			let format = |numbers: &[u64]| {
+				numbers
+					.iter()
+					.map(|n| format!("{n:>2}"))
+					.collect::<Vec<_>>()
+					.join(" ")
+			};
+
+			format!("Card {card:>3}: {} | {}", format(winning), format(&ours))
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[cfg(test)]
+mod tests_day_04 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 13);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 30);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for cards in generated(generate, 50, 0..20, parse) {
+			assert_eq!(cards.len(), 50);
+			part_1(&cards);
+			assert!(part_2(&cards) >= 50);
+		}
+	}
+}
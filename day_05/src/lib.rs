@@ -0,0 +1,510 @@
+use common::error::parse_token;
+use common::interval::{Interval, IntervalSet};
+use common::parse::{self, Block};
+use common::rng::Rng;
+use common::{ParseError, Solution};
+use rayon::prelude::*;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+	type Input = Garden;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Garden, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(garden: &Garden) -> u64 {
+		part_1(garden)
+	}
+
+	fn part_2(garden: &Garden) -> u64 {
+		part_2(garden)
+	}
+}
+
+pub fn part_1(garden: &Garden) -> u64 {
+	let mut distances = vec![0_u64; garden.seeds.len()];
+	for (i, seed) in garden.seeds.iter().enumerate() {
+		let location = compute_seed_location(*seed, garden);
+
+		distances[i] = location;
+	}
+
+	*distances.iter().min().expect("Min value to exist")
+}
+
+pub fn part_2(garden: &Garden) -> u64 {
+	// Seeds come in pairs of range start and count, pushed through each map as half-open ranges.
+	let mut ranges = garden
+		.seeds
+		.chunks_exact(2)
+		.map(|pair| Interval::new(pair[0], pair[0] + pair[1]))
+		.collect::<IntervalSet>();
+
+	for map in garden.maps() {
+		ranges = map.get_dest_ranges(&ranges);
+	}
+
+	ranges.min().expect("Min value to exist")
+}
+
+/// Part 2 by mapping every seed on its own, as a reference for the range splitting in [`part_2`].
+pub fn part_2_brute_force(garden: &Garden) -> u64 {
+	// Seeds come in pairs of range start and count.
+	let seed_ranges = garden
+		.seeds
+		.chunks_exact(2)
+		.map(|pair| (pair[0], pair[1]))
+		.collect::<Vec<_>>();
+
+	seed_ranges
+		.into_par_iter()
+		.map(|(start, count)| {
+			let mut min_distance = u64::MAX;
+
+			for seed in start..(start + count) {
+				let location = compute_seed_location(seed, garden);
+
+				if location < min_distance {
+					min_distance = location;
+				}
+			}
+
+			min_distance
+		})
+		.min()
+		.expect("Min value to exist")
+}
+
+/// Solve part 2 by brute force and by range splitting, or `None` if the input doesn't parse.
+pub fn compare_part_2(input: &str) -> Option<(u64, u64)> {
+	let garden = parse(input).ok()?;
+
+	Some((part_2_brute_force(&garden), part_2(&garden)))
+}
+
+pub fn parse(input: &str) -> Result<Garden, ParseError> {
+	let mut blocks = parse::blocks(input).into_iter();
+
+	let (y, seed_line) = blocks
+		.next()
+		.and_then(|block| block.enumerate().next())
+		.ok_or_else(|| ParseError::end_of_input(input, "a line of seeds"))?;
+	let (_, seeds) = parse::labelled(y, seed_line, "`:` after `seeds`")?;
+	let tokens = seeds.split_whitespace().collect::<Vec<_>>();
+	let seeds = parse::numbers::<u64, Vec<_>>(y, seed_line, seeds, "a seed number")?;
+
+	// Part 2 reads the seeds as pairs of a range start and length, so there must be at least one whole pair.
+	match (seeds.is_empty(), seeds.len() % 2 == 1) {
+		(true, _) => return Err(ParseError::end_of_line(y, seed_line, "a seed number")),
+		(false, true) => {
+			return Err(ParseError::end_of_line(
+				y,
+				seed_line,
+				"a length for the last seed range",
+			))
+		}
+		(false, false) => {}
+	}
+
+	for (pair, tokens) in seeds.chunks_exact(2).zip(tokens.chunks_exact(2)) {
+		let expected = match pair[0].checked_add(pair[1]) {
+			Some(_) if pair[1] > 0 => continue,
+			Some(_) => "a seed range length above 0",
+			None => "a seed range length that doesn't overflow",
+		};
+
+		return Err(ParseError::at(y, seed_line, tokens[1], expected));
+	}
+
+	let mut maps = blocks.map(|block| GardeningMap::parse(&block));
+	let mut next_map = || {
+		maps.next()
+			.unwrap_or_else(|| Err(ParseError::end_of_input(input, "seven maps")))
+	};
+
+	Ok(Garden {
+		seeds,
+		seed_to_soil: next_map()?,
+		soil_to_fertilizer: next_map()?,
+		fertilizer_to_water: next_map()?,
+		water_to_light: next_map()?,
+		light_to_temperature: next_map()?,
+		temperature_to_humidity: next_map()?,
+		humidity_to_location: next_map()?,
+	})
+}
+
+/// An almanac whose seven maps each have about `size` non-overlapping source ranges over the numbers below
+/// `100 * size`. Seed ranges are short so part 2 stays quick to brute force.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	const MAPS: [&str; 7] = [
+		"seed-to-soil",
+		"soil-to-fertilizer",
+		"fertilizer-to-water",
+		"water-to-light",
+		"light-to-temperature",
+		"temperature-to-humidity",
+		"humidity-to-location",
+	];
+
+	let size = size.max(1) as u64;
+	let limit = 100 * size;
+
+	let seeds = (0..rng.range(1..=5))
+		.flat_map(|_| [rng.range(0..=limit), rng.range(1..=50)])
+		.map(|n| n.to_string())
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	let maps = MAPS
+		.iter()
+		.map(|name| {
+			// Cut `0..limit` into pieces and map most of them somewhere else.
+			let mut cuts = (0..size).map(|_| rng.range(0..=limit)).collect::<Vec<_>>();
+			cuts.extend([0, limit]);
+			cuts.sort();
+			cuts.dedup();
+
+			let mut ranges = cuts
+				.windows(2)
+				.filter_map(|cut| {
+					let length = cut[1] - cut[0];
+					let destination = rng.range(0..=limit);
+
+					rng.chance(0.8)
+						.then(|| format!("{destination} {} {length}", cut[0]))
+				})
+				.collect::<Vec<_>>();
+			rng.shuffle(&mut ranges);
+
+			format!("{name} map:\n{}", ranges.join("\n"))
+		})
+		.collect::<Vec<_>>()
+		.join("\n\n");
+
+	format!("seeds: {seeds}\n\n{maps}")
+}
+
+/// Follow one seed through every map to its location.
+pub fn compute_seed_location(seed: u64, garden: &Garden) -> u64 {
+	let soil = garden.seed_to_soil.get_dest(seed);
+	let fertilizer = garden.soil_to_fertilizer.get_dest(soil);
+	let water = garden.fertilizer_to_water.get_dest(fertilizer);
+	let light = garden.water_to_light.get_dest(water);
+	let temperature = garden.light_to_temperature.get_dest(light);
+	let humidity = garden.temperature_to_humidity.get_dest(temperature);
+
+	garden.humidity_to_location.get_dest(humidity)
+}
+
+#[derive(Debug)]
+pub struct Garden {
+	pub seeds: Vec<u64>,
+	pub seed_to_soil: GardeningMap,
+	pub soil_to_fertilizer: GardeningMap,
+	pub fertilizer_to_water: GardeningMap,
+	pub water_to_light: GardeningMap,
+	pub light_to_temperature: GardeningMap,
+	pub temperature_to_humidity: GardeningMap,
+	pub humidity_to_location: GardeningMap,
+}
+
+impl Garden {
+	/// The maps in the order a seed passes through them.
+	pub fn maps(&self) -> [&GardeningMap; 7] {
+		[
+			&self.seed_to_soil,
+			&self.soil_to_fertilizer,
+			&self.fertilizer_to_water,
+			&self.water_to_light,
+			&self.light_to_temperature,
+			&self.temperature_to_humidity,
+			&self.humidity_to_location,
+		]
+	}
+}
+
+#[derive(Debug)]
+pub struct GardeningMap {
+	/// The ranges in input order. Where ranges overlap, the later one wins.
+	pub inner: Vec<Range>,
+}
+
+impl GardeningMap {
+	/// Parse a block of ranges under its `x-to-y map:` header.
+	fn parse(block: &Block) -> Result<Self, ParseError> {
+		let ranges = block
+			.enumerate()
+			.skip(1)
+			.map(|(y, line)| {
+				let parts: Vec<&str> = line.split_ascii_whitespace().collect();
+
+				if parts.len() < 3 {
+					return Err(ParseError::end_of_line(
+						y,
+						line,
+						"a destination start, source start and range length",
+					));
+				}
+
+				let source_start: u64 = parse_token(y, line, parts[1], "a source start")?;
+				let destination_start: u64 = parse_token(y, line, parts[0], "a destination start")?;
+				let range: u64 = parse_token(y, line, parts[2], "a range length")?;
+
+				let source_end = source_start
+					.checked_add(range)
+					.filter(|_| destination_start.checked_add(range).is_some())
+					.ok_or_else(|| {
+						ParseError::at(y, line, parts[2], "a range length that doesn't overflow")
+					})?;
+
+				Ok(Range {
+					source: Interval::new(source_start, source_end),
+					destination_start,
+				})
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { inner: ranges })
+	}
+
+	/// Where a source ends up. Sources outside every range map to themselves.
+	pub fn get_dest(&self, source: u64) -> u64 {
+		for range in self.inner.iter().rev() {
+			if let Some(d) = range.is_in_range(source) {
+				return d;
+			}
+		}
+
+		source
+	}
+
+	/// Map sets of sources to destinations, splitting them wherever they cross a range's edge. Later ranges take
+	/// priority, as in [`GardeningMap::get_dest`].
+	pub fn get_dest_ranges(&self, sources: &IntervalSet) -> IntervalSet {
+		let mut unmapped = sources.clone();
+		let mut mapped = IntervalSet::new();
+
+		for range in self.inner.iter().rev() {
+			let source = IntervalSet::from_iter([range.source]);
+
+			for overlap in unmapped.intersect(&source).iter() {
+				mapped.insert(range.map(overlap));
+			}
+
+			unmapped = unmapped.subtract(&source);
+		}
+
+		mapped.union(&unmapped)
+	}
+}
+
+#[derive(Debug)]
+pub struct Range {
+	pub source: Interval,
+	pub destination_start: u64,
+}
+
+impl Range {
+	/// Where a source ends up, or `None` if this range doesn't cover it.
+	pub fn is_in_range(&self, source: u64) -> Option<u64> {
+		match self.source.contains(source) {
+			true => Some(self.destination_start + (source - self.source.start)),
+			false => None,
+		}
+	}
+
+	/// Where a part of the source interval ends up.
+	pub fn map(&self, sources: &Interval) -> Interval {
+		Interval::new(
+			self.destination_start + (sources.start - self.source.start),
+			self.destination_start + (sources.end - self.source.start),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests_day_05 {
+	use super::*;
+	use common::differential;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 35);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 46);
+	}
+
+	#[test]
+	fn parse_keeps_last_range() {
+		let input = include_str!("part_1_input.txt");
+		let garden = parse(input).unwrap();
+		let last = garden.humidity_to_location.inner.last().unwrap();
+
+		assert!(!input.ends_with('\n'));
+		assert_eq!(
+			(last.destination_start, last.source),
+			(130183265, Interval::new(2386051074, 2386051074 + 290870825))
+		);
+	}
+
+	#[test]
+	fn seeds_come_in_pairs() {
+		let maps = "\n\nseed-to-soil map:\n50 98 2".repeat(7);
+		let error = |seeds: &str| {
+			parse(&format!("seeds:{seeds}{maps}"))
+				.unwrap_err()
+				.to_string()
+		};
+
+		assert_eq!(
+			error(""),
+			"line 1, column 7: expected a seed number, found end of line"
+		);
+		assert_eq!(
+			error(" 79 14 55"),
+			"line 1, column 16: expected a length for the last seed range, found end of line"
+		);
+		assert_eq!(
+			error(" 79 0"),
+			"line 1, column 11: expected a seed range length above 0, found `0`"
+		);
+		assert_eq!(
+			error(" 18446744073709551615 1"),
+			"line 1, column 29: expected a seed range length that doesn't overflow, found `1`"
+		);
+	}
+
+	#[test]
+	fn ranges_must_not_overflow() {
+		let input = format!(
+			"seeds: 79 14\n\nseed-to-soil map:\n50 18446744073709551600 20{}",
+			"\n\nsoil-to-fertilizer map:\n0 15 37".repeat(6)
+		);
+
+		assert_eq!(
+			parse(&input).unwrap_err().to_string(),
+			"line 4, column 25: expected a range length that doesn't overflow, found `20`"
+		);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 993500720);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 4917124);
+	}
+
+	#[test]
+	fn differential_part_2() {
+		if let Err(mismatch) = differential::check(generate, 10, 0..100, compare_part_2) {
+			panic!("{mismatch}");
+		}
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for garden in generated(generate, 20, 0..20, parse) {
+			let nearest_start = garden
+				.seeds
+				.iter()
+				.step_by(2)
+				.map(|seed| compute_seed_location(*seed, &garden))
+				.min()
+				.unwrap();
+
+			part_1(&garden);
+			assert!(part_2(&garden) <= nearest_start);
+		}
+	}
+}
@@ -0,0 +1,228 @@
+use common::parse;
+use common::rng::Rng;
+use common::{ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+	type Input = Vec<Race>;
+	type Part1 = i64;
+	type Part2 = i64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(races: &Vec<Race>) -> i64 {
+		part_1(races)
+	}
+
+	fn part_2(races: &Vec<Race>) -> i64 {
+		part_2(races)
+	}
+}
+
+pub fn part_1(races: &[Race]) -> i64 {
+	races
+		.iter()
+		.map(|race| race.compute_number_of_ways_to_win())
+		.reduce(|acc, wins| acc * wins)
+		.expect("At least two races to exist")
+}
+
+pub fn part_2(races: &[Race]) -> i64 {
+	combine_races(races).compute_number_of_ways_to_win()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+	let numbers: Vec<Vec<i64>> = input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let (_, numbers) = parse::labelled(y, line, "`:` after the label")?;
+
+			parse::numbers(y, line, numbers, "a number")
+		})
+		.collect::<Result<_, _>>()?;
+
+	if numbers.len() < 2 {
+		return Err(ParseError::end_of_input(
+			input,
+			"a line of times and distances",
+		));
+	}
+
+	Ok(numbers[0]
+		.iter()
+		.zip(numbers[1].iter())
+		.map(|(time, distance)| Race {
+			time: *time,
+			distance: *distance,
+		})
+		.collect())
+}
+
+/// Three or four races lasting up to `size` milliseconds, each with a record that can be beaten.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.max(2) as i64;
+
+	let races = (0..rng.range(3..=4))
+		.map(|_| {
+			let time = rng.range((size as u64 / 2).max(2)..=size as u64) as i64;
+			let best = (time / 2) * (time - time / 2);
+
+			(time, rng.range(0..=best as u64 - 1))
+		})
+		.collect::<Vec<_>>();
+
+	let row = |numbers: Vec<String>| {
+		numbers
+			.iter()
+			.map(|n| format!("{n:>5}"))
+			.collect::<String>()
+	};
+
+	format!(
+		"Time:    {}\nDistance:{}",
+		row(races.iter().map(|(time, _)| time.to_string()).collect()),
+		row(races.iter().map(|(_, record)| record.to_string()).collect())
+	)
+}
+
+/// Count each race's ways to win by scanning and by the quadratic formula, including the combined race while it is
+/// short enough to scan, or `None` if the input doesn't parse.
+pub fn compare_ways_to_win(input: &str) -> Option<(Vec<i64>, Vec<i64>)> {
+	let mut races = parse(input).ok()?;
+
+	let digits = |numbers: Vec<i64>| numbers.iter().map(|n| n.to_string().len()).sum::<usize>();
+	let times = digits(races.iter().map(|race| race.time).collect());
+	let distances = digits(races.iter().map(|race| race.distance).collect());
+
+	if !races.is_empty() && times <= 6 && distances <= 12 {
+		races.push(combine_races(&races));
+	}
+
+	Some((
+		races
+			.iter()
+			.map(Race::compute_number_of_ways_to_win_by_scan)
+			.collect(),
+		races
+			.iter()
+			.map(Race::compute_number_of_ways_to_win)
+			.collect(),
+	))
+}
+
+/// The races are really one long race, with the digits split up by bad kerning.
+pub fn combine_races(races: &[Race]) -> Race {
+	let combine = |numbers: Vec<i64>| {
+		numbers
+			.iter()
+			.map(|n| n.to_string())
+			.collect::<String>()
+			.parse::<i64>()
+			.expect("Number to parse")
+	};
+
+	Race {
+		time: combine(races.iter().map(|race| race.time).collect()),
+		distance: combine(races.iter().map(|race| race.distance).collect()),
+	}
+}
+
+#[derive(Debug)]
+pub struct Race {
+	pub time: i64,
+	pub distance: i64,
+}
+
+impl Race {
+	/// Count the hold times that beat the record, which lie between the roots of `hold * (time - hold) = distance`.
+	pub fn compute_number_of_ways_to_win(&self) -> i64 {
+		let discriminant = self.time * self.time - 4 * self.distance;
+
+		if discriminant < 0 {
+			return 0;
+		}
+
+		let root = (discriminant as f64).sqrt();
+		let wins = |hold_time: i64| (self.time - hold_time) * hold_time > self.distance;
+
+		// Float roots can be off by one for large races, so step onto the first and last winning hold times.
+		let mut low = (((self.time as f64 - root) / 2.0).floor() as i64).max(0);
+		let mut high = (((self.time as f64 + root) / 2.0).ceil() as i64).min(self.time);
+
+		while low <= high && !wins(low) {
+			low += 1;
+		}
+		while high >= low && !wins(high) {
+			high -= 1;
+		}
+
+		match low <= high {
+			true => high - low + 1,
+			false => 0,
+		}
+	}
+
+	/// Count the winning hold times by trying every one, as a reference for the quadratic formula.
+	pub fn compute_number_of_ways_to_win_by_scan(&self) -> i64 {
+		(0..=self.time)
+			.map(|hold_time| {
+				let time_left = self.time - hold_time;
+				let distance = time_left * hold_time;
+
+				match distance > self.distance {
+					true => 1,
+					false => 0,
+				}
+			})
+			.sum::<i64>()
+	}
+}
+
+#[cfg(test)]
+mod tests_day_06 {
+	use super::*;
+	use common::differential;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+Time:      7  15   30
+Distance:  9  40  200
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 288);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+Time:      7  15   30
+Distance:  9  40  200
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 71503);
+	}
+
+	#[test]
+	fn differential_ways_to_win() {
+		if let Err(mismatch) = differential::check(generate, 30, 0..200, compare_ways_to_win) {
+			panic!("{mismatch}");
+		}
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for races in generated(generate, 20, 0..5, parse) {
+			assert!(part_1(&races) > 0);
+			assert!(part_2(&races) > 0);
+		}
+	}
+}
@@ -0,0 +1,308 @@
+use common::error::parse_token;
+use common::rng::Rng;
+use common::{ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+	type Input = Vec<Hand>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(hands: &Vec<Hand>) -> u64 {
+		part_1(hands)
+	}
+
+	fn part_2(hands: &Vec<Hand>) -> u64 {
+		part_2(hands)
+	}
+}
+
+pub fn part_1(hands: &[Hand]) -> u64 {
+	let mut hands = hands.to_vec();
+	sort_hands(&mut hands);
+	compute_hand_value_sum(&hands)
+}
+
+pub fn part_2(hands: &[Hand]) -> u64 {
+	let mut hands = hands
+		.iter()
+		.map(|hand| {
+			// Jacks are really jokers in part 2.
+			let cards = hand
+				.cards
+				.iter()
+				.map(|c| match c {
+					Card::Jack => Card::Joker,
+					_ => *c,
+				})
+				.collect::<Vec<_>>();
+
+			Hand {
+				cards_rank: compute_cards_rank(&cards),
+				power: compute_most_powerful_joker(&cards),
+				cards,
+				bid: hand.bid,
+			}
+		})
+		.collect::<Vec<_>>();
+
+	sort_hands(&mut hands);
+	compute_hand_value_sum(&hands)
+}
+
+/// Sort from the strongest hand to the weakest, breaking ties card by card.
+pub fn sort_hands(hands: &mut [Hand]) {
+	hands.sort_by_key(|h| (h.power, h.cards_rank.clone()));
+}
+
+/// The total winnings of hands sorted by [`sort_hands`], where each bid is multiplied by the hand's rank.
+pub fn compute_hand_value_sum(hands: &[Hand]) -> u64 {
+	hands
+		.iter()
+		.rev()
+		.enumerate()
+		.map(|(i, hand)| hand.bid * (i as u64 + 1))
+		.sum()
+}
+
+/// The strongest type the cards can make with every joker standing in for the most common other card.
+pub fn compute_most_powerful_joker(cards: &[Card]) -> Power {
+	let (card, _) = cards
+		.iter()
+		.filter(|c| **c != Card::Joker)
+		.sorted()
+		.group_by(|c| **c)
+		.into_iter()
+		.map(|(c, g)| (c, g.count()))
+		.max_by_key(|(_, c)| *c)
+		.unwrap_or((Card::Ace, 5));
+
+	let replaced_joker = cards
+		.iter()
+		.map(|c| match *c {
+			Card::Joker => card,
+			_ => *c,
+		})
+		.collect::<Vec<_>>();
+
+	compute_power(&replaced_joker)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let (cards, bid) = line
+				.split_once(' ')
+				.ok_or_else(|| ParseError::end_of_line(y, line, "a space before the bid"))?;
+
+			let cards = cards
+				.chars()
+				.enumerate()
+				.map(|(x, c)| match c {
+					'A' => Ok(Card::Ace),
+					'K' => Ok(Card::King),
+					'Q' => Ok(Card::Queen),
+					'J' => Ok(Card::Jack),
+					'T' => Ok(Card::Ten),
+					'9' => Ok(Card::Nine),
+					'8' => Ok(Card::Eight),
+					'7' => Ok(Card::Seven),
+					'6' => Ok(Card::Six),
+					'5' => Ok(Card::Five),
+					'4' => Ok(Card::Four),
+					'3' => Ok(Card::Three),
+					'2' => Ok(Card::Two),
+					_ => Err(ParseError::new(y, x, "a card label", c)),
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			if cards.len() != 5 {
+				return Err(ParseError::at(y, line, bid, "a hand of five cards"));
+			}
+
+			let cards_rank = compute_cards_rank(&cards);
+			let power = compute_power(&cards);
+
+			Ok(Hand {
+				cards,
+				cards_rank,
+				bid: parse_token(y, line, bid, "a bid")?,
+				power,
+			})
+		})
+		.collect()
+}
+
+/// `size` hands with bids. Each hand is drawn from a few labels so every hand type turns up.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	const LABELS: [char; 13] = [
+		'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+	];
+
+	(0..size)
+		.map(|_| {
+			let mut labels = LABELS;
+			rng.shuffle(&mut labels);
+
+			let labels = &labels[..rng.range(1..=5) as usize];
+			let hand = (0..5).map(|_| *rng.choose(labels)).collect::<String>();
+
+			format!("{hand} {}", rng.range(1..=1000))
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// The type of hand the cards make, such as a full house.
+pub fn compute_power(cards: &[Card]) -> Power {
+	let cards: Vec<_> = cards
+		.iter()
+		.sorted()
+		.group_by(|c| **c)
+		.into_iter()
+		.map(|(_, g)| g.count())
+		.sorted()
+		.collect();
+
+	let unique_cards = cards.len();
+
+	match unique_cards {
+		1 => Power::FiveOfAKind,
+		2 => match cards[1] == 4 {
+			true => Power::FourOfAKind,
+			false => Power::FullHouse,
+		},
+		3 => match cards[2] == 3 {
+			true => Power::ThreeOfAKind,
+			false => Power::TwoPair,
+		},
+		4 => Power::OnePair,
+		5 => Power::HighCard,
+		_ => unreachable!("Cannot have more than 5 unique cards"),
+	}
+}
+
+/// A key that sorts hands of the same type card by card, strongest first.
+pub fn compute_cards_rank(cards: &[Card]) -> String {
+	cards
+		.iter()
+		.map(|c| match c {
+			Card::Ace => "0",
+			Card::King => "1",
+			Card::Queen => "2",
+			Card::Jack => "4",
+			Card::Ten => "5",
+			Card::Nine => "6",
+			Card::Eight => "7",
+			Card::Seven => "8",
+			Card::Six => "9",
+			Card::Five => "A",
+			Card::Four => "B",
+			Card::Three => "C",
+			Card::Two => "D",
+			Card::Joker => "E",
+		})
+		.join("")
+}
+
+#[derive(Clone, Debug)]
+pub struct Hand {
+	pub cards: Vec<Card>,
+	/// See [`compute_cards_rank`].
+	pub cards_rank: String,
+	pub bid: u64,
+	pub power: Power,
+}
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Card {
+	Ace,
+	King,
+	Queen,
+	Jack,
+	Ten,
+	Nine,
+	Eight,
+	Seven,
+	Six,
+	Five,
+	Four,
+	Three,
+	Two,
+	Joker,
+}
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Power {
+	FiveOfAKind,
+	FourOfAKind,
+	FullHouse,
+	ThreeOfAKind,
+	TwoPair,
+	OnePair,
+	HighCard,
+}
+
+#[cfg(test)]
+mod tests_day_07 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 6440);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+		assert_eq!(249726565, part_1(&parse(input).unwrap()));
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 5905);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+		assert_eq!(251135960, part_2(&parse(input).unwrap()));
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for hands in generated(generate, 100, 0..20, parse) {
+			assert_eq!(hands.len(), 100);
+			assert!(part_1(&hands) > 0);
+			assert!(part_2(&hands) > 0);
+		}
+	}
+}
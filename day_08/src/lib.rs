@@ -0,0 +1,455 @@
+use common::cycle;
+use common::error::NoAnswer;
+use common::rng::Rng;
+use common::{ParseError, Solution};
+use num::integer::{ExtendedGcd, Integer};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+	type Input = Network;
+	type Part1 = Result<u64, NoAnswer>;
+	type Part2 = Result<u64, NoAnswer>;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Network, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(network: &Network) -> Result<u64, NoAnswer> {
+		part_1(network)
+	}
+
+	fn part_2(network: &Network) -> Result<u64, NoAnswer> {
+		part_2(network)
+	}
+}
+
+pub fn part_1(network: &Network) -> Result<u64, NoAnswer> {
+	if !network.map.contains_key("AAA") {
+		return Err(NoAnswer("there is no node `AAA` to start from".to_string()));
+	}
+
+	compute_traverse_distance("AAA".to_string(), network, |e| e == "ZZZ")
+		.ok_or_else(|| NoAnswer("`ZZZ` can't be reached from `AAA`".to_string()))
+}
+
+pub fn part_2(network: &Network) -> Result<u64, NoAnswer> {
+	let start_nodes: Vec<_> = network
+		.map
+		.keys()
+		.filter_map(|k| match k.ends_with('A') {
+			true => Some(k.clone()),
+			false => None,
+		})
+		.collect();
+
+	start_nodes
+		.into_par_iter()
+		.map(|key| find_finishes(&key, network))
+		.collect::<Vec<_>>()
+		.into_iter()
+		.reduce(Finishes::intersect)
+		.ok_or_else(|| NoAnswer("there are no `..A` nodes to start from".to_string()))?
+		.first()
+		.ok_or_else(|| NoAnswer("the ghosts never all stand on `..Z` nodes at once".to_string()))
+}
+
+/// Follow a ghost until its node and place in the steps repeat, noting every step count that leaves it on a `..Z`
+/// node along the way.
+pub fn find_finishes(start: &str, network: &Network) -> Finishes {
+	let step = |(node, i): &(&str, usize)| {
+		let next = network.map.get(*node).expect("Node to exist");
+		let next = match network.steps[*i] {
+			Step::Left => &next.0,
+			Step::Right => &next.1,
+		};
+
+		(next.as_str(), (i + 1) % network.steps.len())
+	};
+
+	let initial = (start, 0);
+	let cycle = cycle::brent(&initial, step);
+
+	let mut finishes = Finishes {
+		early: vec![],
+		from: cycle.start as u64,
+		period: cycle.length as u64,
+		residues: vec![],
+	};
+
+	let mut state = initial;
+	for n in 0..(cycle.start + cycle.length) as u64 {
+		if state.0.ends_with('Z') {
+			match n < finishes.from {
+				true => finishes.early.push(n),
+				false => finishes.residues.push(n % finishes.period),
+			}
+		}
+
+		state = step(&state);
+	}
+
+	finishes
+}
+
+/// The step counts at which ghosts all stand on `..Z` nodes: each of the `early` counts, then every count from `from`
+/// onwards that is one of the `residues` modulo `period`.
+#[derive(Debug)]
+pub struct Finishes {
+	pub early: Vec<u64>,
+	pub from: u64,
+	pub period: u64,
+	pub residues: Vec<u64>,
+}
+
+impl Finishes {
+	pub fn contains(&self, n: u64) -> bool {
+		self.early.contains(&n) || (n >= self.from && self.residues.contains(&(n % self.period)))
+	}
+
+	/// The step counts at which both sets of ghosts finish. Their loops line up every LCM of the two periods, at the
+	/// counts given by the Chinese remainder theorem.
+	pub fn intersect(self, other: Self) -> Self {
+		let from = self.from.max(other.from);
+		let early = (0..from)
+			.filter(|n| self.contains(*n) && other.contains(*n))
+			.collect();
+
+		let residues = self
+			.residues
+			.iter()
+			.flat_map(|a| {
+				other
+					.residues
+					.iter()
+					.filter_map(|b| crt(*a, self.period, *b, other.period))
+			})
+			.collect();
+
+		Self {
+			early,
+			from,
+			period: num::integer::lcm(self.period, other.period),
+			residues,
+		}
+	}
+
+	/// The smallest step count in the set.
+	pub fn first(&self) -> Option<u64> {
+		let looped = self
+			.residues
+			.iter()
+			.map(|r| self.from + (r + self.period - self.from % self.period) % self.period)
+			.min();
+
+		self.early.first().copied().or(looped)
+	}
+}
+
+/// The number below `lcm(m, n)` that leaves `a` modulo `m` and `b` modulo `n`, if one exists.
+pub fn crt(a: u64, m: u64, b: u64, n: u64) -> Option<u64> {
+	let (m, n) = (m as i128, n as i128);
+	let ExtendedGcd { gcd, x, .. } = m.extended_gcd(&n);
+	let difference = b as i128 - a as i128;
+
+	if difference % gcd != 0 {
+		return None;
+	}
+
+	// `x` inverts `m / gcd` modulo `n / gcd`, so this steps from `a` in multiples of `m` until it also reaches `b`.
+	let k = (difference / gcd * x).rem_euclid(n / gcd);
+
+	Some((a as i128 + m * k).rem_euclid(m / gcd * n) as u64)
+}
+
+/// How many steps it takes to get from `key` to a node that passes `break_check`, or `None` if no such node is ever
+/// reached.
+pub fn compute_traverse_distance<F: Fn(&str) -> bool>(
+	mut key: String,
+	network: &Network,
+	break_check: F,
+) -> Option<u64> {
+	// Once every node has been visited at every place in the steps, the walk can only repeat itself.
+	let limit = network.map.len() * network.steps.len();
+
+	for (i, step) in network.steps.iter().cycle().take(limit).enumerate() {
+		let next = network.map.get(&key).expect("Key to exist");
+		let next = match step {
+			Step::Left => &next.0,
+			Step::Right => &next.1,
+		};
+
+		if break_check(next) {
+			return Some(i as u64 + 1);
+		}
+
+		key = next.clone();
+	}
+
+	None
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+	let lines: Vec<_> = input.lines().collect();
+
+	let first_line = lines
+		.first()
+		.ok_or_else(|| ParseError::end_of_input(input, "a line of steps"))?;
+
+	let steps = first_line
+		.chars()
+		.enumerate()
+		.map(|(x, c)| match c {
+			'L' => Ok(Step::Left),
+			'R' => Ok(Step::Right),
+			_ => Err(ParseError::new(0, x, "`L` or `R`", c)),
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let lines = lines.get(2..).unwrap_or(&[]);
+
+	let map = lines
+		.iter()
+		.enumerate()
+		.map(|(i, line)| {
+			let y = i + 2;
+			let (key, value) = line
+				.split_once(" = ")
+				.ok_or_else(|| ParseError::end_of_line(y, line, "` = ` after the node"))?;
+
+			let value = value.trim_start_matches('(').trim_end_matches(')');
+			let (left, right) = value
+				.split_once(", ")
+				.ok_or_else(|| ParseError::at(y, line, value, "`(left, right)`"))?;
+
+			Ok((y, line, key, left, right))
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let nodes = map
+		.iter()
+		.map(|(_, _, key, _, _)| *key)
+		.collect::<HashSet<_>>();
+	for (y, line, _, left, right) in &map {
+		for next in [left, right] {
+			if !nodes.contains(next) {
+				return Err(ParseError::at(*y, line, next, "the name of a node"));
+			}
+		}
+	}
+
+	let map = map
+		.into_iter()
+		.map(|(_, _, key, left, right)| (key.to_string(), (left.to_string(), right.to_string())))
+		.collect();
+
+	Ok(Network { steps, map })
+}
+
+/// A network where `AAA` and up to four other `..A` nodes each start a ring of up to `size` nodes through their `..Z`
+/// node. Both turns lead the same way, so every ghost reaches its `..Z` node again after the same number of steps.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let steps = (0..rng.range(2..=size.max(2) as u64))
+		.map(|_| match rng.chance(0.5) {
+			true => 'L',
+			false => 'R',
+		})
+		.collect::<String>();
+
+	let mut names = HashSet::new();
+	let mut lines = vec![];
+
+	for ghost in 0..rng.range(1..=5) {
+		let (start, end) = loop {
+			let (start, end) = match ghost {
+				0 => ("AAA".to_string(), "ZZZ".to_string()),
+				_ => {
+					let prefix = random_name(rng, 2);
+					(format!("{prefix}A"), format!("{prefix}Z"))
+				}
+			};
+
+			if !names.contains(&start) && !names.contains(&end) {
+				names.extend([start.clone(), end.clone()]);
+				break (start, end);
+			}
+		};
+
+		let mut ring = vec![start];
+		for _ in 1..rng.range(2..=size.max(2) as u64) {
+			let name = loop {
+				// Filler nodes never end in `A` or `Z`.
+				let name = format!(
+					"{}{}",
+					random_name(rng, 2),
+					(b'B' + rng.index(24) as u8) as char
+				);
+
+				if names.insert(name.clone()) {
+					break name;
+				}
+			};
+
+			ring.push(name);
+		}
+		ring.push(end);
+
+		// The `..Z` node loops back to the node after the start.
+		for (i, node) in ring.iter().enumerate() {
+			let next = match ring.get(i + 1) {
+				Some(next) => next,
+				None => &ring[1],
+			};
+
+			lines.push(format!("{node} = ({next}, {next})"));
+		}
+	}
+
+	rng.shuffle(&mut lines);
+
+	format!("{steps}\n\n{}", lines.join("\n"))
+}
+
+fn random_name(rng: &mut Rng, len: usize) -> String {
+	(0..len)
+		.map(|_| (b'A' + rng.index(26) as u8) as char)
+		.collect()
+}
+
+#[derive(Debug)]
+pub struct Network {
+	pub steps: Vec<Step>,
+	pub map: HashMap<String, (String, String)>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Step {
+	Left,
+	Right,
+}
+
+#[cfg(test)]
+mod tests_day_08 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), Ok(6));
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+		assert_eq!(part_1(&parse(input).unwrap()), Ok(12643));
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), Ok(6));
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+		assert_eq!(part_2(&parse(input).unwrap()), Ok(13133452426987));
+	}
+
+	#[test]
+	fn part_02_offset_loops() {
+		// Ghost 1 finishes at steps 1, 3, 5 and so on, ghost 2 at steps 2, 5, 8 and so on.
+		let input = r#"
+L
+
+11A = (11Z, XXX)
+11Z = (11B, XXX)
+11B = (11Z, XXX)
+22A = (22B, XXX)
+22B = (22Z, XXX)
+22Z = (22C, XXX)
+22C = (22D, XXX)
+22D = (22Z, XXX)
+XXX = (XXX, XXX)
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), Ok(5));
+	}
+
+	#[test]
+	fn nodes_must_exist() {
+		let input = "L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+
+		assert_eq!(
+			parse(input).unwrap_err().to_string(),
+			"line 3, column 8: expected the name of a node, found `BBB`"
+		);
+	}
+
+	#[test]
+	fn unreachable_finishes() {
+		let network = parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+		assert_eq!(
+			part_1(&network),
+			Err(NoAnswer("`ZZZ` can't be reached from `AAA`".to_string()))
+		);
+
+		let network = parse("L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)").unwrap();
+		assert_eq!(
+			part_1(&network),
+			Err(NoAnswer("there is no node `AAA` to start from".to_string()))
+		);
+		assert_eq!(part_2(&network), Ok(1));
+
+		// One ghost finishes on odd steps and the other on even ones.
+		let network = parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)").unwrap();
+		assert_eq!(
+			part_2(&network),
+			Err(NoAnswer(
+				"the ghosts never all stand on `..Z` nodes at once".to_string()
+			))
+		);
+	}
+
+	#[test]
+	fn chinese_remainders() {
+		assert_eq!(crt(1, 2, 2, 3), Some(5));
+		assert_eq!(crt(0, 4, 2, 6), Some(8));
+		assert_eq!(crt(1, 4, 2, 6), None);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for network in generated(generate, 30, 0..20, parse) {
+			let part_1 = part_1(&network).unwrap();
+			assert!(part_1 >= 2);
+			assert_eq!(part_2(&network).unwrap() % part_1, 0);
+		}
+	}
+}
@@ -0,0 +1,171 @@
+use common::error::parse_token;
+use common::rng::Rng;
+use common::{ParseError, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+	type Input = Vec<Vec<Vec<i64>>>;
+	type Part1 = i64;
+	type Part2 = i64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<Vec<Vec<i64>>>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(pyramids: &Vec<Vec<Vec<i64>>>) -> i64 {
+		part_1(pyramids)
+	}
+
+	fn part_2(pyramids: &Vec<Vec<Vec<i64>>>) -> i64 {
+		part_2(pyramids)
+	}
+}
+
+pub fn part_1(pyramids: &[Vec<Vec<i64>>]) -> i64 {
+	pyramids
+		.iter()
+		.map(|pyramid| {
+			pyramid
+				.iter()
+				.rev()
+				.map(|v| *v.last().expect("Last to exist"))
+				.reduce(|acc, e| acc + e)
+				.expect("Reduce to succeed")
+		})
+		.sum()
+}
+
+pub fn part_2(pyramids: &[Vec<Vec<i64>>]) -> i64 {
+	pyramids
+		.iter()
+		.map(|pyramid| {
+			pyramid
+				.iter()
+				.rev()
+				.map(|v| *v.first().expect("First to exist"))
+				.reduce(|acc, e| e - acc)
+				.expect("Reduce to succeed")
+		})
+		.sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Vec<i64>>>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let mut results = vec![];
+
+			let row_1 = line
+				.split_ascii_whitespace()
+				.map(|n| parse_token(y, line, n, "a number"))
+				.collect::<Result<Vec<i64>, _>>()?;
+
+			if row_1.is_empty() {
+				return Err(ParseError::end_of_line(y, line, "a number"));
+			}
+
+			results.push(row_1);
+
+			loop {
+				let last_row = results.last().expect("Last row to exist");
+
+				let row = last_row
+					.windows(2)
+					.map(|window| window[1] - window[0])
+					.collect::<Vec<_>>();
+
+				let all_zeros = row.iter().all(|v| *v == 0);
+
+				results.push(row);
+
+				if all_zeros {
+					break;
+				}
+			}
+
+			Ok(results)
+		})
+		.collect()
+}
+
+/// `size` histories, each a polynomial of degree four or less sampled at up to 21 points. There are always at least
+/// two more points than the degree, so the differences reach a row of zeros.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| {
+			let length = rng.range(6..=21) as i64;
+			let coefficients = (0..=rng.range(0..=4))
+				.map(|_| rng.range(0..=10) as i64 - 5)
+				.collect::<Vec<_>>();
+
+			(0..length)
+				.map(|x| {
+					coefficients
+						.iter()
+						.rev()
+						.fold(0, |acc, coefficient| acc * x + coefficient)
+						.to_string()
+				})
+				.collect::<Vec<_>>()
+				.join(" ")
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[cfg(test)]
+mod tests_day_09 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 114);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 2098530125);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 2);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 1016);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for pyramids in generated(generate, 50, 0..20, parse) {
+			assert_eq!(pyramids.len(), 50);
+			assert!(pyramids.iter().all(|pyramid| pyramid.len() <= 6));
+			part_1(&pyramids);
+			part_2(&pyramids);
+		}
+	}
+}
@@ -0,0 +1,591 @@
+use common::polygon;
+use common::render::Colour;
+use common::rng::Rng;
+use common::{Direction, Grid, ParseError, Point, Solution};
+use log::Level;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+	type Input = Sketch;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Sketch, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(sketch: &Sketch) -> u64 {
+		part_1(sketch)
+	}
+
+	fn part_2(sketch: &Sketch) -> u64 {
+		part_2(sketch)
+	}
+}
+
+pub fn part_1(sketch: &Sketch) -> u64 {
+	let length = sketch.main_loop.len();
+
+	// The tile furthest from the start is halfway around the loop, whichever way it is walked.
+	if log::log_enabled!(Level::Debug) {
+		let mut grid = sketch.grid.clone();
+		for (i, point) in sketch.main_loop.iter().enumerate() {
+			grid[*point].distance = i.min(length - i);
+		}
+
+		log::debug!(
+			"Distances along the main loop\n{}",
+			draw_distances(&grid, sketch.start)
+		);
+	}
+
+	(length / 2) as u64
+}
+
+pub fn part_2(sketch: &Sketch) -> u64 {
+	// The loop's tiles are the vertices of a lattice polygon, so the tiles it encloses are its interior points.
+	let vertices = loop_vertices(sketch);
+
+	// Tracing the enclosed tiles a row at a time is only worth it for the picture.
+	if log::log_enabled!(Level::Debug) {
+		let mut grid = sketch.grid.clone();
+		walk_main_loop(&mut grid, &sketch.main_loop);
+		ray_trace_loop(&mut grid);
+		log::debug!("Tiles enclosed by the main loop\n{}", draw_enclosed(&grid));
+	}
+
+	polygon::interior_points(&vertices) as u64
+}
+
+/// Part 2 by scanning each row and flipping between outside and inside at every wall of the loop, as a cross-check
+/// of the lattice count in [`part_2`].
+pub fn part_2_by_scanline(sketch: &Sketch) -> u64 {
+	let mut grid = sketch.grid.clone();
+
+	walk_main_loop(&mut grid, &sketch.main_loop);
+	ray_trace_loop(&mut grid)
+}
+
+/// Solve part 2 by scanline and by Pick's theorem, or `None` if the input doesn't parse.
+pub fn compare_part_2(input: &str) -> Option<(u64, u64)> {
+	let sketch = parse(input).ok()?;
+
+	Some((part_2_by_scanline(&sketch), part_2(&sketch)))
+}
+
+/// Colour each tile by whether it is the start, part of the main loop, enclosed by it or outside it.
+pub fn render(sketch: &Sketch) -> Grid<Colour> {
+	let mut grid = sketch.grid.clone();
+
+	walk_main_loop(&mut grid, &sketch.main_loop);
+	ray_trace_loop(&mut grid);
+
+	grid.map(|node| match node {
+		node if node.point == sketch.start => Colour::rgb(230, 57, 70),
+		node if node.is_main_loop => Colour::rgb(241, 250, 238),
+		node if node.contained => Colour::rgb(42, 157, 143),
+		_ => Colour::rgb(29, 53, 87),
+	})
+}
+
+pub fn parse(input: &str) -> Result<Sketch, ParseError> {
+	let pipes = Grid::parse(input, "a pipe, `.` or `S`", |c| match c {
+		'|' => Some(Pipe::Vertical),
+		'-' => Some(Pipe::Horizontal),
+		'L' => Some(Pipe::TopRight),
+		'J' => Some(Pipe::TopLeft),
+		'F' => Some(Pipe::BottomRight),
+		'7' => Some(Pipe::BottomLeft),
+		'.' => Some(Pipe::Ground),
+		'S' => Some(Pipe::Start),
+		_ => None,
+	})?;
+
+	let (x, y) = pipes
+		.position(|pipe| *pipe == Pipe::Start)
+		.ok_or_else(|| ParseError::end_of_input(input, "a start tile `S`"))?;
+	let start = Point::new(x, y);
+
+	let nodes = pipes
+		.cells()
+		.map(|((x, y), pipe)| Node {
+			point: Point::new(x, y),
+			pipe: *pipe,
+			distance: 0,
+			is_main_loop: false,
+			contained: false,
+		})
+		.collect();
+
+	let mut grid = Grid::new(pipes.width(), pipes.height(), nodes);
+	replace_start(&mut grid, start)?;
+
+	let main_loop = trace_loop(&grid, start)
+		.ok_or_else(|| ParseError::new(start.y, start.x, "a start tile on a closed loop", 'S'))?;
+
+	Ok(Sketch {
+		grid,
+		start,
+		main_loop,
+	})
+}
+
+/// A sketch about `4 * size` tiles across. The loop runs around a random tree of 4 by 4 blocks, so it never crosses
+/// itself and encloses the middle of every block, which is filled with stray pipes like the rest of the sketch.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.max(1);
+
+	// Grow a tree over about two thirds of a `size` by `size` grid of blocks.
+	let mut in_tree = Grid::filled(size, size, false);
+	let mut links = vec![];
+	let root = Point::new(rng.index(size), rng.index(size));
+	let mut frontier = vec![root];
+	in_tree[root] = true;
+
+	let mut grown = 1;
+	while grown < size * size * 2 / 3 && !frontier.is_empty() {
+		let i = rng.index(frontier.len());
+		let block = frontier[i];
+		let steps = Direction::ALL
+			.into_iter()
+			.filter_map(|direction| {
+				block
+					.step_within(direction, size, size)
+					.filter(|next| !in_tree[*next])
+					.map(|next| (direction, next))
+			})
+			.collect::<Vec<_>>();
+
+		if steps.is_empty() {
+			// The block is boxed in, so it can't grow any further.
+			frontier.swap_remove(i);
+			continue;
+		}
+
+		let (direction, next) = *rng.choose(&steps);
+
+		in_tree[next] = true;
+		links.push((block, direction));
+		frontier.push(next);
+		grown += 1;
+	}
+
+	// Walk the ring of tiles around the edge of each block, opening a gap wherever the tree links two blocks.
+	let mut connections = HashMap::<Point<usize>, Vec<Direction>>::new();
+	// The ring is only ever walked east or south, away from the edge of the sketch.
+	let mut connect = |from: Point<usize>, direction: Direction| {
+		let to = match direction.is_horizontal() {
+			true => Point::new(from.x + 1, from.y),
+			false => Point::new(from.x, from.y + 1),
+		};
+		connections.entry(from).or_default().push(direction);
+		connections.entry(to).or_default().push(direction.reverse());
+	};
+
+	let is_linked = |block: Point<usize>, direction: Direction| {
+		links.contains(&(block, direction))
+			|| block
+				.step(direction)
+				.is_some_and(|other| links.contains(&(other, direction.reverse())))
+	};
+
+	for ((x, y), _) in in_tree.cells().filter(|(_, in_tree)| **in_tree) {
+		let block = Point::new(x, y);
+		let corner = Point::new(1 + 4 * x, 1 + 4 * y);
+		let at = |dx: usize, dy: usize| Point::new(corner.x + dx, corner.y + dy);
+
+		for i in 0..3 {
+			let gap = i == 1;
+
+			if !(gap && is_linked(block, Direction::North)) {
+				connect(at(i, 0), Direction::East);
+			}
+			if !(gap && is_linked(block, Direction::South)) {
+				connect(at(i, 3), Direction::East);
+			}
+			if !(gap && is_linked(block, Direction::West)) {
+				connect(at(0, i), Direction::South);
+			}
+			if !(gap && is_linked(block, Direction::East)) {
+				connect(at(3, i), Direction::South);
+			}
+		}
+
+		if is_linked(block, Direction::East) {
+			connect(at(3, 1), Direction::East);
+			connect(at(3, 2), Direction::East);
+		}
+		if is_linked(block, Direction::South) {
+			connect(at(1, 3), Direction::South);
+			connect(at(2, 3), Direction::South);
+		}
+	}
+
+	let width = 4 * size + 2;
+	let mut sketch = Grid::new(
+		width,
+		width,
+		(0..width * width)
+			.map(|_| *rng.choose(&['|', '-', 'L', 'J', '7', 'F', '.', '.']))
+			.collect(),
+	);
+
+	for (point, directions) in connections.iter() {
+		sketch[*point] = match (
+			directions.contains(&Direction::North),
+			directions.contains(&Direction::East),
+			directions.contains(&Direction::South),
+		) {
+			(true, _, true) => '|',
+			(true, true, _) => 'L',
+			(true, false, false) => 'J',
+			(false, true, true) => 'F',
+			(false, true, false) => '-',
+			(false, false, _) => '7',
+		};
+	}
+
+	// Stray pipes next to the start could look like they connect to it.
+	let mut loop_tiles = connections.keys().copied().collect::<Vec<_>>();
+	loop_tiles.sort();
+	let start = *rng.choose(&loop_tiles);
+	sketch[start] = 'S';
+
+	for neighbour in start.neighbours_4() {
+		if !connections.contains_key(&neighbour) {
+			sketch[neighbour] = '.';
+		}
+	}
+
+	sketch.to_string()
+}
+
+fn replace_start(grid: &mut Grid<Node>, start: Point<usize>) -> Result<(), ParseError> {
+	let can_connect = |direction: Direction| {
+		start
+			.step_within(direction, grid.width(), grid.height())
+			.is_some_and(|point| grid[point].pipe.connects(direction.reverse()))
+	};
+
+	let pipe = match (
+		can_connect(Direction::North),
+		can_connect(Direction::East),
+		can_connect(Direction::South),
+		can_connect(Direction::West),
+	) {
+		(true, true, false, false) => Pipe::TopRight,
+		(true, false, true, false) => Pipe::Vertical,
+		(true, false, false, true) => Pipe::TopLeft,
+		(false, true, true, false) => Pipe::BottomRight,
+		(false, true, false, true) => Pipe::Horizontal,
+		(false, false, true, true) => Pipe::BottomLeft,
+		_ => {
+			return Err(ParseError::new(
+				start.y,
+				start.x,
+				"a start tile connected to exactly two pipes",
+				'S',
+			))
+		}
+	};
+
+	grid[start].pipe = pipe;
+
+	Ok(())
+}
+
+/// The main loop's tiles in the order they are walked from the start, or `None` if a pipe leads off the sketch or into
+/// a tile that doesn't lead back.
+pub fn trace_loop(grid: &Grid<Node>, start: Point<usize>) -> Option<Vec<Point<usize>>> {
+	let mut path = vec![start];
+	let mut previous = start;
+	let mut direction = grid[start].pipe.connections()?[0];
+
+	loop {
+		let current = previous.step_within(direction, grid.width(), grid.height())?;
+
+		if current == start {
+			return Some(path);
+		}
+
+		let [a, b] = grid[current].pipe.connections()?;
+		direction = match (a.reverse() == direction, b.reverse() == direction) {
+			(true, _) => b,
+			(false, true) => a,
+			(false, false) => return None,
+		};
+
+		path.push(current);
+		previous = current;
+	}
+}
+
+/// The main loop as a lattice polygon.
+pub fn loop_vertices(sketch: &Sketch) -> Vec<Point<i64>> {
+	sketch
+		.main_loop
+		.iter()
+		.map(|point| Point::new(point.x as i64, point.y as i64))
+		.collect()
+}
+
+/// Mark every tile along the main loop.
+fn walk_main_loop(grid: &mut Grid<Node>, main_loop: &[Point<usize>]) {
+	for point in main_loop {
+		grid[*point].is_main_loop = true;
+	}
+}
+
+fn ray_trace_loop(grid: &mut Grid<Node>) -> u64 {
+	grid.rows_mut()
+		.map(|row| {
+			let mut inside = false;
+			let mut inside_count = 0;
+
+			row.iter_mut().for_each(|node| {
+				let pipe = node.pipe;
+
+				if !node.is_main_loop && inside {
+					node.contained = true;
+					inside_count += 1;
+				} else if node.is_main_loop
+					&& (pipe == Pipe::TopLeft || pipe == Pipe::TopRight || pipe == Pipe::Vertical)
+				{
+					inside = !inside;
+				}
+			});
+
+			inside_count
+		})
+		.sum()
+}
+
+fn draw_distances(grid: &Grid<Node>, start: Point<usize>) -> String {
+	let mut lines = vec![];
+	for (y, node) in grid.rows().enumerate() {
+		let line: Vec<_> = node
+			.iter()
+			.enumerate()
+			.map(|(x, node)| {
+				let value = match x == start.x && y == start.y {
+					true => "S".to_string(),
+					false => match node.pipe == Pipe::Ground {
+						true => ".".to_string(),
+						false => node.distance.to_string(),
+					},
+				};
+
+				format!("{:>3}", value)
+			})
+			.collect();
+		lines.push(line.join(""));
+	}
+
+	lines.join("\n")
+}
+
+fn draw_enclosed(grid: &Grid<Node>) -> String {
+	let mut lines = vec![];
+	for node in grid.rows() {
+		let line: Vec<_> = node
+			.iter()
+			.map(|node| {
+				let a = match node.is_main_loop {
+					true => node.pipe.to_string(),
+					false => match node.contained {
+						true => "0".to_string(),
+						false => " ".to_string(),
+					},
+				};
+				format!("{:>3}", a)
+			})
+			.collect();
+		lines.push(line.join(""));
+	}
+
+	lines.join("\n")
+}
+
+#[derive(Debug)]
+pub struct Sketch {
+	pub grid: Grid<Node>,
+	pub start: Point<usize>,
+	/// The main loop's tiles in the order they are walked from the start.
+	pub main_loop: Vec<Point<usize>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Node {
+	pub point: Point<usize>,
+	pub pipe: Pipe,
+	pub distance: usize,
+	pub is_main_loop: bool,
+	pub contained: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Pipe {
+	Vertical,
+	Horizontal,
+	TopRight,
+	TopLeft,
+	BottomRight,
+	BottomLeft,
+	Ground,
+	Start,
+}
+
+impl Pipe {
+	/// The two directions a pipe leads in, or `None` for tiles that aren't pipes.
+	pub fn connections(&self) -> Option<[Direction; 2]> {
+		match self {
+			Pipe::Vertical => Some([Direction::North, Direction::South]),
+			Pipe::Horizontal => Some([Direction::West, Direction::East]),
+			Pipe::TopRight => Some([Direction::North, Direction::East]),
+			Pipe::TopLeft => Some([Direction::North, Direction::West]),
+			Pipe::BottomRight => Some([Direction::South, Direction::East]),
+			Pipe::BottomLeft => Some([Direction::South, Direction::West]),
+			Pipe::Ground | Pipe::Start => None,
+		}
+	}
+
+	pub fn connects(&self, direction: Direction) -> bool {
+		self.connections()
+			.is_some_and(|connections| connections.contains(&direction))
+	}
+}
+
+impl Display for Pipe {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Pipe::Vertical => f.write_str("│"),
+			Pipe::Horizontal => f.write_str("─"),
+			Pipe::TopRight => f.write_str("└"),
+			Pipe::TopLeft => f.write_str("┘"),
+			Pipe::BottomRight => f.write_str("┌"),
+			Pipe::BottomLeft => f.write_str("┐"),
+			Pipe::Ground => f.write_str("."),
+			Pipe::Start => f.write_str("S"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests_day_10 {
+	use super::*;
+	use common::differential;
+	use common::polygon::Location;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 8);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 6903);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 10);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 265);
+	}
+
+	#[test]
+	fn parse_error_location() {
+		let input = r#"
+..F7.
+.FJ|.
+SJ.L7
+|F-xJ
+LJ...
+"#
+		.trim();
+
+		assert_eq!(
+			parse(input).unwrap_err().to_string(),
+			"line 4, column 4: expected a pipe, `.` or `S`, found `x`"
+		);
+	}
+
+	#[test]
+	fn open_loop_is_refused() {
+		let input = "S-7\n|.|\nL-.";
+
+		assert_eq!(
+			parse(input).unwrap_err().to_string(),
+			"line 1, column 1: expected a start tile on a closed loop, found `S`"
+		);
+	}
+
+	#[test]
+	fn part_02_matches_scanline() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(compare_part_2(input), Some((265, 265)));
+	}
+
+	#[test]
+	fn differential_part_2() {
+		if let Err(mismatch) = differential::check(generate, 6, 0..30, compare_part_2) {
+			panic!("{mismatch}");
+		}
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for sketch in generated(generate, 8, 0..20, parse) {
+			// Every block on the loop encloses four tiles.
+			let loop_length = part_1(&sketch) * 2;
+			assert_eq!(loop_length % 12, 0);
+			assert!(part_2(&sketch) >= 4);
+
+			let vertices = loop_vertices(&sketch);
+			let inside = sketch
+				.grid
+				.cells()
+				.filter(|((x, y), _)| {
+					polygon::locate(&vertices, Point::new(*x as i64, *y as i64)) == Location::Inside
+				})
+				.count();
+			assert_eq!(part_2(&sketch), inside as u64);
+		}
+	}
+}
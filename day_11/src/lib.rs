@@ -0,0 +1,271 @@
+use common::rng::Rng;
+use common::solution;
+use common::{ParseError, Point, Solution};
+use itertools::Itertools;
+use rayon::prelude::*;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+	type Input = Universe;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = Params;
+
+	fn parse(input: &str) -> Result<Universe, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(universe: &Universe) -> u64 {
+		part_1(universe)
+	}
+
+	fn part_2(universe: &Universe) -> u64 {
+		part_2(universe)
+	}
+
+	fn part_2_with(universe: &Universe, params: &Params) -> u64 {
+		part_2_with(universe, params)
+	}
+}
+
+/// How much older the galaxies are in part 2.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	/// How many rows or columns each empty row or column becomes.
+	pub expansion: u64,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			expansion: 1_000_000,
+		}
+	}
+}
+
+impl solution::Params for Params {
+	/// Empty rows and columns can't shrink to nothing.
+	fn validate(&self) -> Result<(), String> {
+		match self.expansion {
+			0 => Err("`expansion` must be at least 1".to_string()),
+			_ => Ok(()),
+		}
+	}
+}
+
+pub fn part_1(universe: &Universe) -> u64 {
+	universe.travel_galaxies(2)
+}
+
+pub fn part_2(universe: &Universe) -> u64 {
+	part_2_with(universe, &Params::default())
+}
+
+pub fn part_2_with(universe: &Universe, params: &Params) -> u64 {
+	universe.travel_galaxies(params.expansion)
+}
+
+pub fn parse(input: &str) -> Result<Universe, ParseError> {
+	let rows = input.lines().collect::<Vec<_>>();
+
+	let row_len = rows
+		.first()
+		.ok_or_else(|| ParseError::end_of_input(input, "a row of the image"))?
+		.len();
+
+	for (y, row) in rows.iter().enumerate() {
+		if let Some((x, c)) = row
+			.chars()
+			.enumerate()
+			.find(|(_, c)| *c != '#' && *c != '.')
+		{
+			return Err(ParseError::new(y, x, "`#` or `.`", c));
+		}
+
+		if row.len() < row_len {
+			return Err(ParseError::end_of_line(
+				y,
+				row,
+				"a row as long as the first",
+			));
+		} else if row.len() > row_len {
+			return Err(ParseError::at(y, row, &row[row_len..], "end of line"));
+		}
+	}
+
+	let galaxies = rows
+		.iter()
+		.enumerate()
+		.flat_map(|(y, row)| {
+			row.chars()
+				.enumerate()
+				.filter_map(|(x, c)| match c == '#' {
+					true => Some(Point::new(x, y)),
+					false => None,
+				})
+				.collect::<Vec<_>>()
+		})
+		.collect();
+
+	let empty_rows = rows
+		.iter()
+		.enumerate()
+		.filter_map(|(y, row)| match row.chars().all(|c| c == '.') {
+			true => Some(y),
+			false => None,
+		})
+		.collect::<HashSet<_>>();
+
+	let empty_cols = (0..row_len)
+		.filter(|x| {
+			rows.iter()
+				.map(|row| row.chars().nth(*x).expect("Column character to exist"))
+				.all(|c| c == '.')
+		})
+		.collect::<HashSet<_>>();
+
+	Ok(Universe {
+		galaxies,
+		empty_rows,
+		empty_cols,
+	})
+}
+
+/// A `size` by `size` image with a galaxy in about one tile in twenty.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.max(1);
+
+	(0..size)
+		.map(|_| {
+			(0..size)
+				.map(|_| match rng.chance(0.05) {
+					true => '#',
+					false => '.',
+				})
+				.collect::<String>()
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[derive(Debug)]
+pub struct Universe {
+	pub galaxies: Vec<Point<usize>>,
+	pub empty_rows: HashSet<usize>,
+	pub empty_cols: HashSet<usize>,
+}
+
+impl Universe {
+	/// The sum of the distances between every pair of galaxies, with each empty row and column `scaling` wide.
+	pub fn travel_galaxies(&self, scaling: u64) -> u64 {
+		let combinations = self
+			.galaxies
+			.iter()
+			.tuple_combinations()
+			.collect::<Vec<_>>();
+
+		combinations
+			.par_iter()
+			.map(|(l, r)| {
+				let x_distance = compute_distance(l.x, r.x, &self.empty_cols, scaling);
+				let y_distance = compute_distance(l.y, r.y, &self.empty_rows, scaling);
+
+				x_distance + y_distance
+			})
+			.sum()
+	}
+}
+
+/// The distance from `l` to `r` along one axis, where each empty line in `spaces` is `scaling` wide.
+#[inline]
+pub fn compute_distance(l: usize, r: usize, spaces: &HashSet<usize>, scaling: u64) -> u64 {
+	let crosses = compute_crosses(l, r, spaces);
+
+	l.abs_diff(r) as u64 + (crosses * scaling) - crosses
+}
+
+/// How many of the empty lines in `spaces` lie between `l` and `r`.
+#[inline]
+pub fn compute_crosses(l: usize, r: usize, spaces: &HashSet<usize>) -> u64 {
+	let min = l.min(r);
+	let max = l.max(r);
+	let traverses = (min..=max).collect();
+
+	spaces.intersection(&traverses).count() as u64
+}
+
+#[cfg(test)]
+mod tests_day_11 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 374);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = r#"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 82000210);
+	}
+
+	#[test]
+	fn part_02_other_expansions() {
+		let input = r#"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+"#
+		.trim();
+		let universe = parse(input).unwrap();
+
+		assert_eq!(part_2_with(&universe, &Params { expansion: 10 }), 1030);
+		assert_eq!(part_2_with(&universe, &Params { expansion: 100 }), 8410);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for universe in generated(generate, 40, 0..20, parse) {
+			assert!(part_1(&universe) <= part_2(&universe));
+		}
+	}
+}
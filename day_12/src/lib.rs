@@ -0,0 +1,306 @@
+use common::error::parse_token;
+use common::rng::Rng;
+use common::solution;
+use common::{ParseError, Solution};
+use itertools::Itertools;
+use rayon::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+	type Input = Vec<SpringConditions>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = Params;
+
+	fn parse(input: &str) -> Result<Vec<SpringConditions>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(conditions: &Vec<SpringConditions>) -> u64 {
+		part_1(conditions)
+	}
+
+	fn part_2(conditions: &Vec<SpringConditions>) -> u64 {
+		part_2(conditions)
+	}
+
+	fn part_2_with(conditions: &Vec<SpringConditions>, params: &Params) -> u64 {
+		part_2_with(conditions, params)
+	}
+}
+
+/// How the records are unfolded in part 2.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	/// How many copies of each record are joined together.
+	pub unfold: usize,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self { unfold: 5 }
+	}
+}
+
+impl solution::Params for Params {
+	/// Each record has to be kept at least once.
+	fn validate(&self) -> Result<(), String> {
+		match self.unfold {
+			0 => Err("`unfold` must be at least 1".to_string()),
+			_ => Ok(()),
+		}
+	}
+}
+
+pub fn part_1(conditions: &[SpringConditions]) -> u64 {
+	conditions
+		.par_iter()
+		.map(|c| {
+			let mut memo = HashMap::new();
+			compute(&mut memo, c.conditions.as_bytes(), &c.damages, None)
+		})
+		.sum()
+}
+
+pub fn part_2(conditions: &[SpringConditions]) -> u64 {
+	part_2_with(conditions, &Params::default())
+}
+
+pub fn part_2_with(conditions: &[SpringConditions], params: &Params) -> u64 {
+	conditions
+		.par_iter()
+		.map(|c| {
+			let mut memo = HashMap::new();
+			let conditions = (0..params.unfold).map(|_| &c.conditions).join("?");
+			let damages = (0..params.unfold)
+				.flat_map(|_| &c.damages)
+				.cloned()
+				.collect::<Vec<_>>();
+
+			compute(&mut memo, conditions.as_bytes(), &damages, None)
+		})
+		.sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<SpringConditions>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let (springs, numbers) = line
+				.split_once(' ')
+				.ok_or_else(|| ParseError::end_of_line(y, line, "a space before the damages"))?;
+
+			if let Some((x, c)) = springs
+				.chars()
+				.enumerate()
+				.find(|(_, c)| !matches!(c, '?' | '#' | '.'))
+			{
+				return Err(ParseError::new(y, x, "`?`, `#` or `.`", c));
+			}
+
+			let numbers = numbers
+				.split(',')
+				.map(|n| parse_token(y, line, n, "a number of damaged springs"))
+				.collect::<Result<_, _>>()?;
+
+			Ok(SpringConditions {
+				conditions: springs.to_string(),
+				damages: numbers,
+			})
+		})
+		.collect()
+}
+
+/// `size` rows of up to 20 springs. Each row is drawn from a real arrangement with some springs hidden behind `?`, so
+/// every row has at least one arrangement.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| {
+			let mut springs = (0..rng.range(1..=20))
+				.map(|_| match rng.chance(0.5) {
+					true => b'#',
+					false => b'.',
+				})
+				.collect::<Vec<_>>();
+
+			if !springs.contains(&b'#') {
+				let i = rng.index(springs.len());
+				springs[i] = b'#';
+			}
+
+			let damages = springs
+				.split(|spring| *spring == b'.')
+				.filter(|group| !group.is_empty())
+				.map(|group| group.len().to_string())
+				.collect::<Vec<_>>()
+				.join(",");
+
+			let conditions = springs
+				.iter()
+				.map(|spring| match rng.chance(0.5) {
+					true => '?',
+					false => *spring as char,
+				})
+				.collect::<String>();
+
+			format!("{conditions} {damages}")
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[inline]
+pub fn base_case(input: &[u8], numbers: &[usize], offset: Option<usize>) -> Option<u64> {
+	if !input.is_empty() {
+		return match numbers.is_empty() && offset.is_some() {
+			true => Some(0),
+			false => None,
+		};
+	}
+
+	if numbers.is_empty() && offset.is_none() {
+		return Some(1);
+	}
+
+	match (numbers.len(), offset) {
+		(1, Some(o)) if o == numbers[0] => Some(1),
+		_ => Some(0),
+	}
+}
+
+/// How many ways the unknown springs in `input` can be filled in to match the damaged groups in `numbers`. `offset`
+/// is the length of the damaged group being read, if any.
+pub fn compute(
+	memo: &mut MemoCache,
+	input: &[u8],
+	numbers: &[usize],
+	offset: Option<usize>,
+) -> u64 {
+	if let Some(base) = base_case(input, numbers, offset) {
+		return base;
+	}
+
+	let memo_key = MemoKey {
+		input_length: input.len(),
+		offset,
+		number_length: numbers.len(),
+	};
+
+	if let Some(memo_value) = memo.get(&memo_key) {
+		return *memo_value;
+	}
+
+	let next = &input[1..];
+	let permutations = match input[0] {
+		b'?' => match offset {
+			Some(o) => match o == numbers[0] {
+				true => {
+					compute(memo, next, numbers, Some(o + 1))
+						+ compute(memo, next, &numbers[1..], None)
+				}
+				false => compute(memo, next, numbers, Some(o + 1)),
+			},
+			None => compute(memo, next, numbers, Some(1)) + compute(memo, next, numbers, None),
+		},
+		b'#' => match offset {
+			Some(o) => compute(memo, next, numbers, Some(o + 1)),
+			None => compute(memo, next, numbers, Some(1)),
+		},
+		b'.' => match offset {
+			Some(o) => match o == numbers[0] {
+				true => compute(memo, next, &numbers[1..], None),
+				false => 0,
+			},
+			None => compute(memo, next, numbers, None),
+		},
+		_ => unreachable!(),
+	};
+
+	memo.insert(memo_key, permutations);
+
+	permutations
+}
+
+#[derive(Debug)]
+pub struct SpringConditions {
+	pub conditions: String,
+	pub damages: Vec<usize>,
+}
+
+pub type MemoCache = HashMap<MemoKey, u64>;
+
+/// What's left to arrange. Only lengths are kept, as the remaining springs and groups are always suffixes of the row.
+#[derive(Eq, PartialEq, Hash)]
+pub struct MemoKey {
+	pub input_length: usize,
+	pub offset: Option<usize>,
+	pub number_length: usize,
+}
+
+#[cfg(test)]
+mod tests_day_12 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 21);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 525152);
+	}
+
+	#[test]
+	fn part_02_without_unfolding() {
+		let input = r#"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"#
+		.trim();
+		let conditions = parse(input).unwrap();
+
+		assert_eq!(
+			part_2_with(&conditions, &Params { unfold: 1 }),
+			part_1(&conditions)
+		);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for conditions in generated(generate, 50, 0..20, parse) {
+			assert!(part_1(&conditions) >= 50);
+			assert!(part_2(&conditions) >= part_1(&conditions));
+		}
+	}
+}
@@ -0,0 +1,326 @@
+use common::parse::{self, Block};
+use common::rng::Rng;
+use common::{Grid, ParseError, Solution};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+	type Input = Vec<Grid<char>>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(patterns: &Vec<Grid<char>>) -> u64 {
+		part_1(patterns)
+	}
+
+	fn part_2(patterns: &Vec<Grid<char>>) -> u64 {
+		part_2(patterns)
+	}
+}
+
+pub fn part_1(patterns: &[Grid<char>]) -> u64 {
+	patterns
+		.iter()
+		.flat_map(|block| {
+			let vertical = find_vertical_reflection(block);
+			let horizontal = find_horizontal_reflection(block).map(|h| (h) * 100);
+
+			if vertical.is_none() && horizontal.is_none() {
+				log::warn!("No reflection found in pattern\n{}", draw(block));
+			}
+
+			vec![vertical, horizontal]
+		})
+		.flatten()
+		.sum()
+}
+
+pub fn part_2(patterns: &[Grid<char>]) -> u64 {
+	patterns
+		.par_iter()
+		.flat_map(|block| {
+			let vertical = find_flipped_vertical_reflection(block);
+			let horizontal = find_flipped_horizontal_reflection(block).map(|h| (h) * 100);
+
+			if vertical.is_none() && horizontal.is_none() {
+				log::warn!("No smudged reflection found in pattern\n{}", draw(block));
+			}
+
+			vec![vertical, horizontal]
+		})
+		.flatten()
+		.sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+	let blocks = parse::blocks(input);
+
+	if blocks.is_empty() {
+		return Err(ParseError::end_of_input(input, "a pattern"));
+	}
+
+	blocks.iter().map(collect_block).collect()
+}
+
+/// `size` patterns of up to 15 by 15. Each has exactly one line of reflection and exactly one other line that is a
+/// single smudge away from reflecting.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size.max(1))
+		.map(|_| generate_pattern(rng).to_string())
+		.collect::<Vec<_>>()
+		.join("\n\n")
+}
+
+fn generate_pattern(rng: &mut Rng) -> Grid<char> {
+	loop {
+		let width = rng.range(5..=15) as usize;
+		let height = rng.range(5..=15) as usize;
+		let cells = (0..width * height)
+			.map(|_| match rng.chance(0.5) {
+				true => '#',
+				false => '.',
+			})
+			.collect();
+		let mut grid = Grid::new(width, height, cells);
+
+		// Reflect the columns about one line, then the rows about another.
+		let vertical = rng.range(1..=width as u64 - 1) as usize;
+		let vertical_reach = vertical.min(width - vertical);
+		for y in 0..height {
+			for k in 0..vertical_reach {
+				grid[(vertical + k, y)] = grid[(vertical - 1 - k, y)];
+			}
+		}
+
+		let horizontal = rng.range(1..=height as u64 - 1) as usize;
+		let horizontal_reach = horizontal.min(height - horizontal);
+		if 2 * horizontal == height {
+			continue;
+		}
+
+		for k in 0..horizontal_reach {
+			for x in 0..width {
+				grid[(x, horizontal + k)] = grid[(x, horizontal - 1 - k)];
+			}
+		}
+
+		// Smudge a row the horizontal reflection doesn't reach, so only the vertical one is broken.
+		let y = match horizontal < height - horizontal {
+			true => rng.range(2 * horizontal as u64..=height as u64 - 1),
+			false => rng.range(0..=(height - 2 * horizontal_reach) as u64 - 1),
+		} as usize;
+		let x =
+			rng.range((vertical - vertical_reach) as u64..=(vertical + vertical_reach) as u64 - 1);
+		flip_smudge(&mut grid, x as usize, y);
+
+		if rng.chance(0.5) {
+			grid = grid.transpose();
+		}
+
+		// Random cells can line up into extra reflections, so check there is exactly one of each.
+		let reflections = [
+			find_vertical_reflection(&grid),
+			find_horizontal_reflection(&grid),
+		];
+		let flipped = [
+			find_flipped_vertical_reflection(&grid),
+			find_flipped_horizontal_reflection(&grid),
+		];
+
+		if reflections.iter().flatten().count() == 1 && flipped.iter().flatten().count() == 1 {
+			return grid;
+		}
+	}
+}
+
+fn collect_block(block: &Block) -> Result<Grid<char>, ParseError> {
+	Grid::parse(&block.text(), "`#` or `.`", |c| {
+		matches!(c, '#' | '.').then_some(c)
+	})
+	.map_err(|e| ParseError {
+		line: e.line + block.start,
+		..e
+	})
+}
+
+/// The pattern with its rows indented, for log messages.
+fn draw(pattern: &Grid<char>) -> String {
+	pattern
+		.rows()
+		.map(|row| row.iter().map(|c| format!(" {c}")).collect::<String>())
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// How many columns are left of the line of reflection.
+pub fn find_vertical_reflection(pattern: &Grid<char>) -> Option<u64> {
+	find_reflection(pattern, None).map(|r| r as u64)
+}
+
+/// How many rows are above the line of reflection.
+pub fn find_horizontal_reflection(pattern: &Grid<char>) -> Option<u64> {
+	find_reflection(&pattern.transpose(), None).map(|r| r as u64)
+}
+
+/// The new vertical line of reflection once the one smudge is cleaned up.
+pub fn find_flipped_vertical_reflection(pattern: &Grid<char>) -> Option<u64> {
+	find_flipped_reflection(pattern.clone())
+}
+
+/// The new horizontal line of reflection once the one smudge is cleaned up.
+pub fn find_flipped_horizontal_reflection(pattern: &Grid<char>) -> Option<u64> {
+	find_flipped_reflection(pattern.transpose())
+}
+
+fn find_flipped_reflection(mut grid: Grid<char>) -> Option<u64> {
+	let original_reflection = find_reflection(&grid, None);
+
+	for y in 0..grid.height() {
+		for x in 0..grid.width() {
+			flip_smudge(&mut grid, x, y);
+			let reflection = find_reflection(&grid, original_reflection);
+			flip_smudge(&mut grid, x, y);
+
+			if let Some(r) = reflection {
+				return Some(r as u64);
+			}
+		}
+	}
+
+	None
+}
+
+fn flip_smudge(grid: &mut Grid<char>, x: usize, y: usize) {
+	let cell = &mut grid[(x, y)];
+
+	match cell {
+		'#' => *cell = '.',
+		'.' => *cell = '#',
+		_ => unreachable!(),
+	}
+}
+
+fn find_reflection(grid: &Grid<char>, except: Option<usize>) -> Option<usize> {
+	let except = except.unwrap_or(0);
+
+	let reflections = grid
+		.rows()
+		.map(|line| {
+			(1..line.len())
+				.filter(|midpoint| does_reflect(line, *midpoint) && *midpoint != except)
+				.collect::<HashSet<_>>()
+		})
+		.reduce(|acc, h| acc.intersection(&h).cloned().collect())
+		.expect("Final hash to exist");
+
+	reflections.into_iter().next()
+}
+
+fn does_reflect(chars: &[char], midpoint: usize) -> bool {
+	let mut min = midpoint - 1;
+	let mut max = midpoint;
+
+	let mut mirrors = true;
+	loop {
+		let left = chars[min];
+		let right = chars[max];
+
+		if left != right {
+			mirrors = false;
+			break;
+		}
+
+		if min == 0 || max == chars.len() - 1 {
+			break;
+		}
+
+		min -= 1;
+		max += 1;
+	}
+
+	mirrors
+}
+
+#[cfg(test)]
+mod tests_day_13 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 405);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 36041);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 400);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 35915);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for patterns in generated(generate, 20, 0..20, parse) {
+			assert_eq!(patterns.len(), 20);
+			assert!(part_1(&patterns) >= 20);
+			assert!(part_2(&patterns) >= 20);
+		}
+	}
+}
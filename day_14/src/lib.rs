@@ -0,0 +1,416 @@
+use common::animate::OnFrame;
+use common::cycle;
+use common::rng::Rng;
+use common::solution;
+use common::{Grid, ParseError, Solution};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+	type Input = Grid<Rock>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = Params;
+
+	fn parse(input: &str) -> Result<Grid<Rock>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(grid: &Grid<Rock>) -> u64 {
+		part_1(grid)
+	}
+
+	fn part_2(grid: &Grid<Rock>) -> u64 {
+		part_2(grid)
+	}
+
+	fn part_2_with(grid: &Grid<Rock>, params: &Params) -> u64 {
+		part_2_with(grid, params)
+	}
+}
+
+/// How long the platform is spun for in part 2.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	/// How many spin cycles to run before weighing the load.
+	pub cycles: usize,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			cycles: 1_000_000_000,
+		}
+	}
+}
+
+impl solution::Params for Params {
+	/// The platform has to be spun at least once.
+	fn validate(&self) -> Result<(), String> {
+		match self.cycles {
+			0 => Err("`cycles` must be at least 1".to_string()),
+			_ => Ok(()),
+		}
+	}
+}
+
+pub fn part_1(grid: &Grid<Rock>) -> u64 {
+	let mut lines = grid.clone();
+
+	tilt_north(&mut lines);
+
+	compute_weight(&lines)
+}
+
+pub fn part_2(grid: &Grid<Rock>) -> u64 {
+	part_2_with(grid, &Params::default())
+}
+
+pub fn part_2_with(grid: &Grid<Rock>, params: &Params) -> u64 {
+	// The platform soon settles into a loop of layouts, so the billionth is one already seen.
+	let result = cycle::state_at_hashed(grid.clone(), spin, params.cycles);
+
+	compute_weight(&result)
+}
+
+pub fn parse(input: &str) -> Result<Grid<Rock>, ParseError> {
+	Grid::parse(input, "`O`, `#` or `.`", |c| match c {
+		'O' => Some(Rock::Round),
+		'#' => Some(Rock::Cube),
+		'.' => Some(Rock::Empty),
+		_ => None,
+	})
+}
+
+/// A `size` by `size` platform of round and cube-shaped rocks.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| {
+			(0..size)
+				.map(|_| match rng.index(20) {
+					0..=3 => 'O',
+					4..=6 => '#',
+					_ => '.',
+				})
+				.collect::<String>()
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Show the platform after every tilt, spinning until it returns to a layout it has been in before.
+pub fn animate(grid: &Grid<Rock>, on_frame: OnFrame) {
+	let mut lines = grid.clone();
+	let mut seen = HashSet::new();
+	let tilts = [
+		("north", tilt_north as fn(&mut Grid<Rock>)),
+		("west", tilt_west),
+		("south", tilt_south),
+		("east", tilt_east),
+	];
+
+	on_frame(
+		&format_args!("Start, load {}\n{lines}", compute_weight(&lines)),
+		false,
+	);
+	seen.insert(lines.clone());
+
+	for cycle in 1.. {
+		for (name, tilt) in tilts {
+			tilt(&mut lines);
+
+			// The last tilt of a cycle that repeats an earlier layout ends the animation.
+			let last = name == "east" && seen.contains(&lines);
+
+			on_frame(
+				&format_args!(
+					"Cycle {cycle}, tilted {name}, load {}\n{lines}",
+					compute_weight(&lines)
+				),
+				last,
+			);
+		}
+
+		if !seen.insert(lines.clone()) {
+			break;
+		}
+	}
+}
+
+/// Tilt north, west, south then east.
+pub fn spin(grid: &Grid<Rock>) -> Grid<Rock> {
+	let mut lines = grid.clone();
+
+	tilt_north(&mut lines);
+	tilt_west(&mut lines);
+	tilt_south(&mut lines);
+	tilt_east(&mut lines);
+
+	lines
+}
+
+/// The load on the north support beams, where each round rock weighs as much as its distance from the south edge.
+pub fn compute_weight(grid: &Grid<Rock>) -> u64 {
+	grid.rows()
+		.enumerate()
+		.map(|(y, line)| {
+			let height = grid.height() - y;
+			let count = line.iter().filter(|r| **r == Rock::Round).count();
+
+			(height * count) as u64
+		})
+		.sum::<u64>()
+}
+
+/// Roll every round rock north until it hits a cube rock, another round rock or the edge.
+pub fn tilt_north(grid: &mut Grid<Rock>) {
+	let len = grid.width();
+
+	for y in 0..grid.height() {
+		for x in 0..len {
+			tilt_tile_north(grid, x, y);
+		}
+	}
+}
+
+#[inline]
+fn tilt_tile_north(grid: &mut Grid<Rock>, x: usize, y: usize) {
+	if matches!(grid[(x, y)], Rock::Cube | Rock::Round) {
+		return;
+	}
+
+	for n_y in (y + 1)..grid.height() {
+		let next = grid[(x, n_y)];
+
+		if matches!(next, Rock::Cube) {
+			break;
+		}
+
+		if matches!(next, Rock::Round) {
+			grid[(x, y)] = Rock::Round;
+			grid[(x, n_y)] = Rock::Empty;
+			break;
+		}
+	}
+}
+
+/// Roll every round rock west until it hits a cube rock, another round rock or the edge.
+pub fn tilt_west(grid: &mut Grid<Rock>) {
+	let len = grid.width();
+	for y in 0..grid.height() {
+		for x in 0..len {
+			tilt_tile_west(grid, x, y);
+		}
+	}
+}
+
+#[inline]
+fn tilt_tile_west(grid: &mut Grid<Rock>, x: usize, y: usize) {
+	if matches!(grid[(x, y)], Rock::Cube | Rock::Round) {
+		return;
+	}
+
+	for n_x in (x + 1)..grid.width() {
+		let next = grid[(n_x, y)];
+
+		if matches!(next, Rock::Cube) {
+			break;
+		}
+
+		if matches!(next, Rock::Round) {
+			grid[(x, y)] = Rock::Round;
+			grid[(n_x, y)] = Rock::Empty;
+			break;
+		}
+	}
+}
+
+/// Roll every round rock south until it hits a cube rock, another round rock or the edge.
+pub fn tilt_south(grid: &mut Grid<Rock>) {
+	let len = grid.width();
+	for y in (0..grid.height()).rev() {
+		for x in 0..len {
+			tilt_tile_south(grid, x, y);
+		}
+	}
+}
+
+#[inline]
+fn tilt_tile_south(grid: &mut Grid<Rock>, x: usize, y: usize) {
+	if matches!(grid[(x, y)], Rock::Cube | Rock::Round) {
+		return;
+	}
+
+	for n_y in (0..y).rev() {
+		let next = grid[(x, n_y)];
+
+		if matches!(next, Rock::Cube) {
+			break;
+		}
+
+		if matches!(next, Rock::Round) {
+			grid[(x, y)] = Rock::Round;
+			grid[(x, n_y)] = Rock::Empty;
+			break;
+		}
+	}
+}
+
+/// Roll every round rock east until it hits a cube rock, another round rock or the edge.
+pub fn tilt_east(grid: &mut Grid<Rock>) {
+	let len = grid.width();
+	for y in 0..grid.height() {
+		for x in (0..len).rev() {
+			tilt_tile_east(grid, x, y);
+		}
+	}
+}
+
+#[inline]
+fn tilt_tile_east(grid: &mut Grid<Rock>, x: usize, y: usize) {
+	if matches!(grid[(x, y)], Rock::Cube | Rock::Round) {
+		return;
+	}
+
+	for n_x in (0..x).rev() {
+		let next = grid[(n_x, y)];
+
+		if matches!(next, Rock::Cube) {
+			break;
+		}
+
+		if matches!(next, Rock::Round) {
+			grid[(x, y)] = Rock::Round;
+			grid[(n_x, y)] = Rock::Empty;
+			break;
+		}
+	}
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Rock {
+	Round,
+	Cube,
+	Empty,
+}
+
+impl Display for Rock {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let symbol = match self {
+			Rock::Round => 'O',
+			Rock::Cube => '#',
+			Rock::Empty => '.',
+		};
+
+		write!(f, "{symbol}")
+	}
+}
+
+#[cfg(test)]
+mod tests_day_14 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 136);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 64);
+	}
+
+	#[test]
+	fn part_02_fewer_cycles() {
+		let input = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#
+		.trim();
+		let grid = parse(input).unwrap();
+
+		assert_eq!(
+			part_2_with(&grid, &Params { cycles: 3 }),
+			compute_weight(&spin(&spin(&spin(&grid))))
+		);
+		assert_eq!(
+			part_2_with(&grid, &Params { cycles: 0 }),
+			compute_weight(&grid)
+		);
+	}
+
+	#[test]
+	fn animate_example() {
+		let input = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#
+		.trim();
+
+		let mut frames = Vec::new();
+		animate(&parse(input).unwrap(), &mut |frame, _| {
+			frames.push(frame.to_string())
+		});
+
+		assert_eq!(frames[0], format!("Start, load 104\n{input}"));
+		assert!(frames[1].starts_with("Cycle 1, tilted north, load 136\n"));
+		assert_eq!(frames.len() % 4, 1);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for grid in generated(generate, 20, 0..20, parse) {
+			part_1(&grid);
+			part_2(&grid);
+		}
+	}
+}
@@ -0,0 +1,181 @@
+use common::error::parse_token;
+use common::rng::Rng;
+use common::{ParseError, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+	type Input = Vec<Step>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(steps: &Vec<Step>) -> u64 {
+		part_1(steps)
+	}
+
+	fn part_2(steps: &Vec<Step>) -> u64 {
+		part_2(steps)
+	}
+}
+
+pub fn part_1(steps: &[Step]) -> u64 {
+	steps.iter().map(|step| hash_str(&step.text)).sum()
+}
+
+pub fn part_2(steps: &[Step]) -> u64 {
+	let mut hashmap: Vec<Vec<Lens>> = vec![vec![]; 256];
+
+	steps.iter().for_each(|step| {
+		let key = step.key.as_str();
+		let value = step.value;
+
+		let hash = hash_str(key) as usize;
+		let bucket = &mut hashmap[hash];
+		let lens_index = bucket.iter().position(|lens| lens.key == key);
+
+		match (lens_index, value) {
+			(Some(i), Some(n)) => {
+				// Have a previous value, and a new value, replace it.
+				bucket[i].value = n;
+			}
+			(Some(i), None) => {
+				// Have a previous value, but no new value, remove it.
+				bucket.remove(i);
+			}
+			(None, Some(n)) => {
+				// Have no previous value, but have new value, add it.
+				bucket.push(Lens { key, value: n })
+			}
+			(None, None) => (),
+		}
+	});
+
+	hashmap
+		.iter()
+		.enumerate()
+		.map(|(bucket_index, bucket)| {
+			bucket
+				.iter()
+				.enumerate()
+				.map(|(value_index, lens)| {
+					((bucket_index + 1) * (value_index + 1) * (lens.value as usize)) as u64
+				})
+				.sum::<u64>()
+		})
+		.sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+	input
+		.split(',')
+		.map(|step| {
+			let (key, value) = step.split_once(['=', '-']).ok_or_else(|| {
+				ParseError::at(0, input, &step[step.len()..], "`=` or `-` after the label")
+			})?;
+
+			let value = match value.is_empty() {
+				true => None,
+				false => Some(parse_token(0, input, value, "a focal length")?),
+			};
+
+			Ok(Step {
+				text: step.to_string(),
+				key: key.to_string(),
+				value,
+			})
+		})
+		.collect()
+}
+
+/// An initialization sequence of `size` steps over about `size / 2` labels, so lenses are often replaced or removed.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let labels = (0..(size / 2).max(1))
+		.map(|_| {
+			(0..rng.range(1..=6))
+				.map(|_| (b'a' + rng.index(26) as u8) as char)
+				.collect::<String>()
+		})
+		.collect::<Vec<_>>();
+
+	(0..size)
+		.map(|_| {
+			let label = rng.choose(&labels);
+
+			match rng.chance(0.3) {
+				true => format!("{label}-"),
+				false => format!("{label}={}", rng.range(1..=9)),
+			}
+		})
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+/// The Holiday ASCII String Helper algorithm, which hashes a step or label to a box from 0 to 255.
+pub fn hash_str(input: &str) -> u64 {
+	input
+		.chars()
+		.map(|c| c as u64)
+		.fold(0, |hash, v| ((hash + v) * 17) % 256)
+}
+
+#[derive(Debug)]
+pub struct Step {
+	pub text: String,
+	pub key: String,
+	pub value: Option<u64>,
+}
+
+/// A lens in a box, with its label and focal length.
+#[derive(Clone, Debug)]
+pub struct Lens<'a> {
+	pub key: &'a str,
+	pub value: u64,
+}
+
+#[cfg(test)]
+mod tests_day_15 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 1320);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 509167);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 145);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 259333);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for steps in generated(generate, 200, 0..20, parse) {
+			assert_eq!(steps.len(), 200);
+			part_1(&steps);
+			part_2(&steps);
+		}
+	}
+}
@@ -0,0 +1,395 @@
+use common::animate::{Canvas, OnFrame};
+use common::render::Colour;
+use common::rng::Rng;
+use common::{Direction, Grid, ParseError, Point, Solution};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+	type Input = Grid<Tile>;
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(grid: &Grid<Tile>) -> u64 {
+		part_1(grid)
+	}
+
+	fn part_2(grid: &Grid<Tile>) -> u64 {
+		part_2(grid)
+	}
+}
+
+pub fn part_1(grid: &Grid<Tile>) -> u64 {
+	run_beam(Beam::new(0, 0, Direction::East), grid)
+}
+
+pub fn part_2(grid: &Grid<Tile>) -> u64 {
+	// Generate starting positions along the walls of the grid.
+	let start_beams = [
+		(0..grid.width())
+			.map(|x| Beam::new(x, 0, Direction::South))
+			.collect::<Vec<_>>(),
+		(0..grid.height())
+			.map(|y| Beam::new(grid.width() - 1, y, Direction::West))
+			.collect::<Vec<_>>(),
+		(0..grid.width())
+			.map(|x| Beam::new(x, grid.height() - 1, Direction::South))
+			.collect::<Vec<_>>(),
+		(0..grid.height())
+			.map(|y| Beam::new(0, y, Direction::West))
+			.collect::<Vec<_>>(),
+	]
+	.concat();
+
+	start_beams
+		.into_par_iter()
+		.map(|b| run_beam(b, grid))
+		.max()
+		.unwrap_or(0)
+}
+
+pub fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+	Grid::parse(input, "a mirror, splitter or `.`", |c| match c {
+		'.' => Some(Tile::Empty),
+		'/' => Some(Tile::Mirror(MirrorDirection::Forwards)),
+		'\\' => Some(Tile::Mirror(MirrorDirection::Backwards)),
+		'|' => Some(Tile::Splitter(SplitterDirection::Vertical)),
+		'-' => Some(Tile::Splitter(SplitterDirection::Horizontal)),
+		_ => None,
+	})
+}
+
+/// A `size` by `size` contraption with a mirror or splitter on about one tile in seven.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| {
+			(0..size)
+				.map(|_| match rng.chance(0.15) {
+					true => *rng.choose(&['/', '\\', '|', '-']),
+					false => '.',
+				})
+				.collect::<String>()
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Colour the tiles energized by part 1's beam, drawing mirrors and splitters on top.
+pub fn render(grid: &Grid<Tile>) -> Grid<Colour> {
+	let energized = energize(Beam::new(0, 0, Direction::East), grid);
+
+	let mut image = grid.map(|tile| match tile {
+		Tile::Empty => Colour::rgb(20, 20, 30),
+		Tile::Mirror(_) => Colour::rgb(120, 160, 200),
+		Tile::Splitter(_) => Colour::rgb(200, 120, 160),
+	});
+
+	for point in energized {
+		let tile = &mut image[point];
+		*tile = tile.mix(Colour::rgb(255, 210, 60), 0.75);
+	}
+
+	image
+}
+
+/// Show part 1's beam spreading one step at a time, with the head of each beam drawn as an arrow over the tiles
+/// energized so far.
+pub fn animate(grid: &Grid<Tile>, on_frame: OnFrame) {
+	let mut step = 0;
+
+	energize_with(Beam::new(0, 0, Direction::East), grid, |beams, visited| {
+		let energized = visited.iter().map(|v| v.0).collect::<HashSet<_>>();
+		let frame = Canvas::new(grid, |point, tile| {
+			let head = beams.iter().find(|beam| beam.position == point);
+
+			match (head, energized.contains(&point)) {
+				(Some(beam), _) => match beam.direction {
+					Direction::North => '^',
+					Direction::East => '>',
+					Direction::South => 'v',
+					Direction::West => '<',
+				},
+				(None, true) if *tile == Tile::Empty => '#',
+				_ => tile.symbol(),
+			}
+		});
+
+		step += 1;
+		on_frame(
+			&format_args!(
+				"Step {step}, {} beams, {} energized\n{frame}",
+				beams.len(),
+				energized.len()
+			),
+			beams.is_empty(),
+		);
+	});
+}
+
+/// How many tiles the beam energizes.
+pub fn run_beam(beam: Beam, grid: &Grid<Tile>) -> u64 {
+	energize(beam, grid).len() as u64
+}
+
+/// Every tile the beam passes through.
+pub fn energize(beam: Beam, grid: &Grid<Tile>) -> HashSet<Point<usize>> {
+	energize_with(beam, grid, |_, _| {})
+}
+
+/// Every tile the beam passes through, calling `on_step` with the beams about to move on each step and
+/// once more when every beam has stopped.
+pub fn energize_with(
+	beam: Beam,
+	grid: &Grid<Tile>,
+	mut on_step: impl FnMut(&[Beam], &HashSet<(Point<usize>, Direction)>),
+) -> HashSet<Point<usize>> {
+	let mut beams = vec![beam];
+
+	let mut visited = HashSet::new();
+
+	while !beams.is_empty() {
+		on_step(&beams, &visited);
+
+		let next_beams = beams
+			.into_iter()
+			.filter_map(|beam| step_beam(beam, grid, &mut visited))
+			.flatten()
+			.collect::<Vec<_>>();
+
+		beams = next_beams;
+	}
+
+	on_step(&beams, &visited);
+
+	visited.into_iter().map(|v| v.0).collect()
+}
+
+/// Move the beam one tile, turning or splitting it on the tile it is on. `None` means every resulting beam has left
+/// the grid or is retracing a path that was already followed.
+pub fn step_beam(
+	beam: Beam,
+	grid: &Grid<Tile>,
+	visited: &mut HashSet<(Point<usize>, Direction)>,
+) -> Option<Vec<Beam>> {
+	if visited.contains(&(beam.position, beam.direction)) {
+		return None;
+	}
+
+	visited.insert((beam.position, beam.direction));
+
+	let beams = match &grid[beam.position] {
+		Tile::Empty => vec![beam],
+		Tile::Mirror(mirror_direction) => {
+			// `/` turns vertical beams right and horizontal beams left, `\` the opposite.
+			let direction = match (mirror_direction, beam.direction.is_vertical()) {
+				(MirrorDirection::Forwards, true) | (MirrorDirection::Backwards, false) => {
+					beam.direction.turn_right()
+				}
+				(MirrorDirection::Forwards, false) | (MirrorDirection::Backwards, true) => {
+					beam.direction.turn_left()
+				}
+			};
+			let beam = beam.rotate(direction);
+
+			vec![beam]
+		}
+		Tile::Splitter(split_direction) => match split_direction {
+			SplitterDirection::Vertical if beam.direction.is_horizontal() => beam.split(),
+			SplitterDirection::Horizontal if beam.direction.is_vertical() => beam.split(),
+			_ => vec![beam],
+		},
+	};
+
+	let next_beams = beams
+		.into_iter()
+		.flat_map(|b| {
+			b.position
+				.step_within(b.direction, grid.width(), grid.height())
+				.map(|p| Beam {
+					position: p,
+					direction: b.direction,
+				})
+		})
+		.collect::<Vec<_>>();
+
+	match next_beams.is_empty() {
+		true => None,
+		false => Some(next_beams),
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct Beam {
+	pub position: Point<usize>,
+	pub direction: Direction,
+}
+
+impl Beam {
+	pub fn new(x: usize, y: usize, direction: Direction) -> Self {
+		Self {
+			position: Point::new(x, y),
+			direction,
+		}
+	}
+
+	/// The same beam heading in `direction` instead.
+	pub fn rotate(self, direction: Direction) -> Self {
+		Self {
+			position: self.position,
+			direction,
+		}
+	}
+
+	/// Split into two beams heading out to either side.
+	pub fn split(self) -> Vec<Self> {
+		let left = self.direction.turn_left();
+		let right = self.direction.turn_right();
+
+		vec![self.clone().rotate(left), self.rotate(right)]
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum Tile {
+	Empty,
+	Mirror(MirrorDirection),
+	Splitter(SplitterDirection),
+}
+
+impl Tile {
+	/// The character the tile is written as in the input.
+	pub fn symbol(&self) -> char {
+		match self {
+			Tile::Empty => '.',
+			Tile::Mirror(MirrorDirection::Forwards) => '/',
+			Tile::Mirror(MirrorDirection::Backwards) => '\\',
+			Tile::Splitter(SplitterDirection::Vertical) => '|',
+			Tile::Splitter(SplitterDirection::Horizontal) => '-',
+		}
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum MirrorDirection {
+	Forwards,
+	Backwards,
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum SplitterDirection {
+	Vertical,
+	Horizontal,
+}
+
+#[cfg(test)]
+mod tests_day_16 {
+	use super::*;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 46);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 7242);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 51);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 7572);
+	}
+
+	#[test]
+	fn animate_example() {
+		let input = r#"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#
+		.trim();
+
+		let mut frames = Vec::new();
+		animate(&parse(input).unwrap(), &mut |frame, _| {
+			frames.push(frame.to_string())
+		});
+
+		assert!(frames[0].starts_with("Step 1, 1 beams, 0 energized\n>|...\\....\n"));
+		assert!(frames.last().unwrap().contains(", 46 energized\n"));
+	}
+
+	#[test]
+	fn parse_error_location() {
+		let input = r#"
+.|...\....
+|.-.\..#..
+"#
+		.trim();
+
+		assert_eq!(
+			parse(input).unwrap_err().to_string(),
+			"line 2, column 8: expected a mirror, splitter or `.`, found `#`"
+		);
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for grid in generated(generate, 20, 0..20, parse) {
+			assert!(part_1(&grid) >= 1);
+			assert!(part_2(&grid) >= 1);
+		}
+	}
+}
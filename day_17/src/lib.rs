@@ -0,0 +1,571 @@
+use common::animate::{Canvas, OnFrame};
+use common::error::NoAnswer;
+use common::render::Colour;
+use common::rng::Rng;
+use common::solution;
+use common::{Direction, Grid, ParseError, Point, Solution};
+use pathfinding::prelude::astar;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+	type Input = Grid<u64>;
+	type Part1 = Result<u64, NoAnswer>;
+	type Part2 = Result<u64, NoAnswer>;
+	type Params = Params;
+
+	fn parse(input: &str) -> Result<Grid<u64>, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(grid: &Grid<u64>) -> Result<u64, NoAnswer> {
+		part_1(grid)
+	}
+
+	fn part_2(grid: &Grid<u64>) -> Result<u64, NoAnswer> {
+		part_2(grid)
+	}
+
+	fn part_1_with(grid: &Grid<u64>, params: &Params) -> Result<u64, NoAnswer> {
+		part_1_with(grid, params)
+	}
+
+	fn part_2_with(grid: &Grid<u64>, params: &Params) -> Result<u64, NoAnswer> {
+		part_2_with(grid, params)
+	}
+}
+
+/// How far each part's crucible moves in a straight line.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	pub crucible_min: u64,
+	pub crucible_max: u64,
+	pub ultra_crucible_min: u64,
+	pub ultra_crucible_max: u64,
+}
+
+impl Params {
+	/// The crucible for part 1.
+	pub fn crucible(&self) -> Crucible {
+		Crucible {
+			min: self.crucible_min,
+			max: self.crucible_max,
+		}
+	}
+
+	/// The ultra crucible for part 2.
+	pub fn ultra_crucible(&self) -> Crucible {
+		Crucible {
+			min: self.ultra_crucible_min,
+			max: self.ultra_crucible_max,
+		}
+	}
+
+	/// The crucible for the given part.
+	pub fn for_part(&self, part: u8) -> Crucible {
+		match part {
+			1 => self.crucible(),
+			_ => self.ultra_crucible(),
+		}
+	}
+}
+
+impl solution::Params for Params {
+	/// Each crucible has to be able to move, and to stop as soon as it may turn.
+	fn validate(&self) -> Result<(), String> {
+		for (name, crucible) in [
+			("crucible", self.crucible()),
+			("ultra_crucible", self.ultra_crucible()),
+		] {
+			if crucible.max == 0 {
+				return Err(format!("`{name}_max` must be at least 1"));
+			}
+
+			if crucible.min > crucible.max {
+				return Err(format!(
+					"`{name}_min` ({}) must not be more than `{name}_max` ({})",
+					crucible.min, crucible.max
+				));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			crucible_min: Crucible::STANDARD.min,
+			crucible_max: Crucible::STANDARD.max,
+			ultra_crucible_min: Crucible::ULTRA.min,
+			ultra_crucible_max: Crucible::ULTRA.max,
+		}
+	}
+}
+
+/// How many blocks a crucible must move in a straight line before it can turn or stop, and how many it can move
+/// before it must turn.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Crucible {
+	pub min: u64,
+	pub max: u64,
+}
+
+impl Crucible {
+	pub const STANDARD: Self = Self { min: 1, max: 3 };
+	pub const ULTRA: Self = Self { min: 4, max: 10 };
+
+	/// Why the crucible has no path across a grid.
+	pub fn no_path(&self) -> NoAnswer {
+		NoAnswer(format!(
+			"a crucible moving {} to {} blocks before turning can't reach the bottom right",
+			self.min, self.max
+		))
+	}
+}
+
+pub fn part_1(grid: &Grid<u64>) -> Result<u64, NoAnswer> {
+	part_1_with(grid, &Params::default())
+}
+
+pub fn part_1_with(grid: &Grid<u64>, params: &Params) -> Result<u64, NoAnswer> {
+	let crucible = params.crucible();
+
+	find_path(grid, crucible).ok_or_else(|| crucible.no_path())
+}
+
+pub fn part_2(grid: &Grid<u64>) -> Result<u64, NoAnswer> {
+	part_2_with(grid, &Params::default())
+}
+
+pub fn part_2_with(grid: &Grid<u64>, params: &Params) -> Result<u64, NoAnswer> {
+	let crucible = params.ultra_crucible();
+
+	find_path(grid, crucible).ok_or_else(|| crucible.no_path())
+}
+
+pub fn parse(input: &str) -> Result<Grid<u64>, ParseError> {
+	Grid::parse(input, "a digit", |c| c.to_digit(10).map(u64::from))
+}
+
+/// A `size` by `size` map of heat loss digits. It is at least 5 across, so the ultra crucible can always reach the end.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.max(5);
+
+	(0..size)
+		.map(|_| {
+			(0..size)
+				.map(|_| rng.range(1..=9).to_string())
+				.collect::<String>()
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Shade each block by its heat loss and mark the cheapest path for the given part's crucible.
+pub fn render(grid: &Grid<u64>, part: u8, params: &Params) -> Result<Grid<Colour>, NoAnswer> {
+	let crucible = params.for_part(part);
+	let (path, _) = shortest_path(grid, crucible).ok_or_else(|| crucible.no_path())?;
+
+	let mut image = grid.map(|heat_loss| {
+		Colour::rgb(250, 240, 200).mix(Colour::rgb(120, 40, 20), (*heat_loss as f64 - 1.0) / 8.0)
+	});
+
+	for state in path {
+		image[state.position] = Colour::rgb(30, 110, 230);
+	}
+
+	Ok(image)
+}
+
+/// Show the search for the given part's crucible one expanded state at a time, then the cheapest path it found.
+pub fn animate(
+	grid: &Grid<u64>,
+	part: u8,
+	params: &Params,
+	on_frame: OnFrame,
+) -> Result<(), NoAnswer> {
+	let crucible = params.for_part(part);
+	let mut expanded = HashSet::new();
+	let mut on_expand = |state: &State| {
+		expanded.insert(state.position);
+
+		let frame = Canvas::new(grid, |point, heat_loss| {
+			match (point == state.position, expanded.contains(&point)) {
+				(true, _) => '@',
+				(false, true) => '.',
+				(false, false) => digit(*heat_loss),
+			}
+		});
+
+		on_frame(
+			&format_args!(
+				"{} blocks expanded, at {}\n{frame}",
+				expanded.len(),
+				state.position
+			),
+			false,
+		);
+	};
+
+	let (path, heat_loss) =
+		shortest_path_with(grid, crucible, &mut on_expand).ok_or_else(|| crucible.no_path())?;
+
+	let path = path
+		.iter()
+		.map(|state| state.position)
+		.collect::<HashSet<_>>();
+	let frame = Canvas::new(grid, |point, heat_loss| match path.contains(&point) {
+		true => '#',
+		false => digit(*heat_loss),
+	});
+
+	on_frame(&format_args!("Heat loss {heat_loss}\n{frame}"), true);
+
+	Ok(())
+}
+
+fn digit(heat_loss: u64) -> char {
+	char::from_digit(heat_loss as u32, 10).unwrap_or('?')
+}
+
+/// The least heat lost on the way from the top left to the bottom right, moving at least `crucible.min` and at most
+/// `crucible.max` blocks before turning, or `None` if the crucible can't get there.
+pub fn find_path(grid: &Grid<u64>, crucible: Crucible) -> Option<u64> {
+	shortest_path(grid, crucible).map(|(_, heat_loss)| heat_loss)
+}
+
+/// The cheapest path itself, as each state along it, and its heat loss.
+pub fn shortest_path(grid: &Grid<u64>, crucible: Crucible) -> Option<(Vec<State>, u64)> {
+	shortest_path_with(grid, crucible, |_| {})
+}
+
+/// Search for the cheapest path, calling `on_expand` with each state as its successors are generated.
+pub fn shortest_path_with(
+	grid: &Grid<u64>,
+	crucible: Crucible,
+	mut on_expand: impl FnMut(&State),
+) -> Option<(Vec<State>, u64)> {
+	let start = State {
+		position: Point::new(0, 0),
+		direction: None,
+		distance: 0,
+	};
+
+	let end = Point::new(grid.width() - 1, grid.height() - 1);
+
+	astar(
+		&start,
+		|state| {
+			on_expand(state);
+
+			match (state.direction, state.distance >= crucible.min) {
+				(Some(direction), false) => compute_next_successor(state, direction, grid),
+				_ => compute_neighbouring_successors(state, grid, &start, crucible.max),
+			}
+		},
+		|state| end.manhattan(state.position) as u64,
+		|state| state.position == end && state.distance >= crucible.min,
+	)
+}
+
+fn compute_neighbouring_successors(
+	state: &State,
+	grid: &Grid<u64>,
+	start: &State,
+	max: u64,
+) -> Vec<(State, u64)> {
+	Direction::ALL
+		.into_iter()
+		.flat_map(|direction| {
+			// 1. Find all valid neighbouring points relative to the current position.
+
+			state
+				.position
+				.step_within(direction, grid.width(), grid.height())
+				.map(|point| (point, direction, grid[point]))
+		})
+		.filter(|(position, direction, _)| {
+			// 2. Do not use any points which would cause us to backtrack.
+
+			state.direction != Some(direction.reverse()) && *position != start.position
+		})
+		.flat_map(|(position, direction, weight)| {
+			// 3. Create successors that are within max distance.
+
+			let distance = match state.direction == Some(direction) {
+				true => state.distance + 1,
+				false => 1,
+			};
+
+			match distance <= max {
+				true => {
+					let next_state = State {
+						position,
+						direction: Some(direction),
+						distance,
+					};
+					Some((next_state, weight))
+				}
+				false => None,
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+fn compute_next_successor(
+	state: &State,
+	direction: Direction,
+	grid: &Grid<u64>,
+) -> Vec<(State, u64)> {
+	match state
+		.position
+		.step_within(direction, grid.width(), grid.height())
+	{
+		Some(point) => {
+			let weight = grid[point];
+			let new_state = State {
+				position: point,
+				direction: Some(direction),
+				distance: state.distance + 1,
+			};
+
+			vec![(new_state, weight)]
+		}
+		None => Vec::with_capacity(0),
+	}
+}
+
+/// A crucible on a block, with the way it last moved and how many blocks it has gone in a straight line. It has no
+/// direction before its first move.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct State {
+	pub position: Point<usize>,
+	pub direction: Option<Direction>,
+	pub distance: u64,
+}
+
+#[cfg(test)]
+mod tests_day_17 {
+	use super::*;
+	use common::rng::generated;
+	use std::collections::HashMap;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), Ok(102));
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), Ok(1044));
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), Ok(94));
+	}
+
+	#[test]
+	fn part_01_with_ultra_crucible() {
+		let input = r#"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+"#
+		.trim();
+		let params = Params {
+			crucible_min: 4,
+			crucible_max: 10,
+			..Params::default()
+		};
+
+		assert_eq!(part_1_with(&parse(input).unwrap(), &params), Ok(94));
+	}
+
+	#[test]
+	fn unreachable_end() {
+		let input = r#"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+"#
+		.trim();
+		let grid = parse(input).unwrap();
+
+		// Moving exactly five blocks at a time only reaches every fifth row and column, and the end is twelve blocks away.
+		let params = Params {
+			crucible_min: 5,
+			crucible_max: 5,
+			..Params::default()
+		};
+		let error = "a crucible moving 5 to 5 blocks before turning can't reach the bottom right";
+
+		assert_eq!(
+			part_1_with(&grid, &params),
+			Err(NoAnswer(error.to_string()))
+		);
+		assert_eq!(cheapest(&grid, params.crucible()), None);
+		assert!(render(&grid, 1, &params).is_err());
+
+		// The crucible has to move before it can stop.
+		assert!(part_1(&parse("1").unwrap()).is_err());
+	}
+
+	#[test]
+	fn animate_example() {
+		let input = r#"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+"#
+		.trim();
+
+		let mut frames = Vec::new();
+		let params = Params::default();
+		animate(&parse(input).unwrap(), 1, &params, &mut |frame, last| {
+			frames.push((frame.to_string(), last))
+		})
+		.unwrap();
+
+		let (first, _) = &frames[0];
+		let (path, last) = frames.last().unwrap();
+
+		assert!(first.starts_with("1 blocks expanded, at (0, 0)\n@413432311323\n"));
+		assert!(path.starts_with("Heat loss 102\n###"));
+		assert!(*last);
+		assert_eq!(frames.iter().filter(|(_, last)| *last).count(), 1);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), Ok(1227));
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for grid in generated(generate, 12, 0..10, parse) {
+			assert_eq!(part_1(&grid).ok(), cheapest(&grid, Crucible::STANDARD));
+			assert_eq!(part_2(&grid).ok(), cheapest(&grid, Crucible::ULTRA));
+		}
+	}
+
+	/// The least heat lost, found by relaxing every position, direction and run of straight blocks until none gets any
+	/// cheaper, to check the search against.
+	fn cheapest(grid: &Grid<u64>, crucible: Crucible) -> Option<u64> {
+		let (width, height) = (grid.width(), grid.height());
+		let end = Point::new(width - 1, height - 1);
+		let mut best = HashMap::new();
+		let mut pending = Direction::ALL
+			.into_iter()
+			.filter_map(|direction| {
+				Point::new(0, 0)
+					.step_within(direction, width, height)
+					.map(|p| ((p, direction, 1), grid[p]))
+			})
+			.collect::<Vec<_>>();
+
+		while let Some((state, heat_loss)) = pending.pop() {
+			if best.get(&state).is_some_and(|best| *best <= heat_loss) {
+				continue;
+			}
+
+			best.insert(state, heat_loss);
+			let (position, direction, run) = state;
+
+			for next in Direction::ALL {
+				let straight = next == direction;
+				let allowed = match straight {
+					true => run < crucible.max,
+					false => next != direction.reverse() && run >= crucible.min,
+				};
+
+				if let (true, Some(point)) = (allowed, position.step_within(next, width, height)) {
+					let run = match straight {
+						true => run + 1,
+						false => 1,
+					};
+
+					pending.push(((point, next, run), heat_loss + grid[point]));
+				}
+			}
+		}
+
+		best.into_iter()
+			.filter(|((position, _, run), _)| *position == end && *run >= crucible.min)
+			.map(|(_, heat_loss)| heat_loss)
+			.min()
+	}
+}
@@ -0,0 +1,372 @@
+use common::error::parse_token;
+use common::polygon;
+use common::render::{Colour, Polygon};
+use common::rng::Rng;
+use common::{Direction, ParseError, Point, Solution};
+use itertools::Itertools;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+	type Input = DigPlan;
+	type Part1 = i64;
+	type Part2 = i64;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<DigPlan, ParseError> {
+		parse(input)
+	}
+
+	fn part_1(plan: &DigPlan) -> i64 {
+		part_1(plan)
+	}
+
+	fn part_2(plan: &DigPlan) -> i64 {
+		part_2(plan)
+	}
+}
+
+pub fn part_1(plan: &DigPlan) -> i64 {
+	compute_area(&plan.instructions)
+}
+
+pub fn part_2(plan: &DigPlan) -> i64 {
+	compute_area(&plan.colour_instructions)
+}
+
+pub fn parse(input: &str) -> Result<DigPlan, ParseError> {
+	Ok(DigPlan {
+		instructions: parse_1(input)?,
+		colour_instructions: parse_2(input)?,
+	})
+}
+
+/// A dig plan of `4 * size` instructions, tracing a row of `size` overlapping bars clockwise. The colour codes trace a
+/// different, much larger row of bars with the same number of instructions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.max(1);
+	let plan = generate_bars(rng, size, 10);
+	let colour_plan = generate_bars(rng, size, 100_000);
+
+	plan.iter()
+		.zip(colour_plan.iter())
+		.map(
+			|((direction, distance), (colour_direction, colour_distance))| {
+				let code = |direction: &Direction, codes: [char; 4]| match direction {
+					Direction::East => codes[0],
+					Direction::South => codes[1],
+					Direction::West => codes[2],
+					Direction::North => codes[3],
+				};
+
+				format!(
+					"{} {distance} (#{colour_distance:05x}{})",
+					code(direction, ['R', 'D', 'L', 'U']),
+					code(colour_direction, ['0', '1', '2', '3'])
+				)
+			},
+		)
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// The outline of `count` side by side bars up to `scale` wide, where each bar overlaps the next vertically.
+fn generate_bars(rng: &mut Rng, count: usize, scale: i64) -> Vec<(Direction, i64)> {
+	let mut bars = vec![(0, rng.range(1..=scale as u64) as i64)];
+
+	while bars.len() < count {
+		let (top, bottom) = *bars.last().expect("First bar to exist");
+		let next_top = top + rng.range(0..=2 * scale as u64) as i64 - scale;
+		let next_bottom = next_top + rng.range(1..=2 * scale as u64) as i64;
+
+		if next_top < bottom && next_bottom > top && next_top != top && next_bottom != bottom {
+			bars.push((next_top, next_bottom));
+		}
+	}
+
+	let vertical = |from: i64, to: i64| match to > from {
+		true => (Direction::South, to - from),
+		false => (Direction::North, from - to),
+	};
+
+	let mut instructions = vec![];
+
+	for (i, (top, _)) in bars.iter().enumerate() {
+		instructions.push((Direction::East, rng.range(1..=scale as u64) as i64));
+
+		if let Some((next_top, _)) = bars.get(i + 1) {
+			instructions.push(vertical(*top, *next_top));
+		}
+	}
+
+	let (last_top, last_bottom) = bars[count - 1];
+	instructions.push(vertical(last_top, last_bottom));
+
+	// Retrace the bars' widths along the bottom, from right to left.
+	let widths = instructions
+		.iter()
+		.filter(|(direction, _)| *direction == Direction::East)
+		.map(|(_, width)| *width)
+		.collect::<Vec<_>>();
+
+	for (i, (_, bottom)) in bars.iter().enumerate().rev() {
+		instructions.push((Direction::West, widths[i]));
+
+		if let Some((_, previous_bottom)) = i.checked_sub(1).map(|i| bars[i]) {
+			instructions.push(vertical(*bottom, previous_bottom));
+		}
+	}
+
+	let (first_top, first_bottom) = bars[0];
+	instructions.push(vertical(first_bottom, first_top));
+
+	instructions
+}
+
+/// The instructions as written, such as `R 6`.
+pub fn parse_1(input: &str) -> Result<Vec<Instruction>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let split = line.split(' ').collect::<Vec<_>>();
+			if split.len() < 2 {
+				return Err(ParseError::end_of_line(y, line, "a direction and distance"));
+			}
+
+			let code = split[0];
+			let distance = parse_token(y, line, split[1], "a distance")?;
+
+			let direction = match code {
+				"R" => Direction::East,
+				"D" => Direction::South,
+				"L" => Direction::West,
+				"U" => Direction::North,
+				_ => return Err(ParseError::at(y, line, code, "`R`, `D`, `L` or `U`")),
+			};
+
+			Ok(Instruction {
+				direction,
+				distance,
+			})
+		})
+		.collect()
+}
+
+/// The instructions hidden in the colour codes, where the first five hex digits are the distance and the last the
+/// direction.
+pub fn parse_2(input: &str) -> Result<Vec<Instruction>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(y, line)| {
+			let split = line.split(' ').collect::<Vec<_>>();
+			let hex = split
+				.get(2)
+				.ok_or_else(|| ParseError::end_of_line(y, line, "a colour code"))?
+				.trim_start_matches(['(', '#'])
+				.trim_end_matches(')');
+
+			if hex.len() != 6 || !hex.is_char_boundary(5) {
+				return Err(ParseError::at(y, line, hex, "a six digit hex code"));
+			}
+
+			let distance = &hex[..5];
+			let distance = i64::from_str_radix(distance, 16)
+				.map_err(|_| ParseError::at(y, line, distance, "a hex distance"))?;
+			let code = &hex[5..];
+
+			let direction = match code {
+				"0" => Direction::East,
+				"1" => Direction::South,
+				"2" => Direction::West,
+				"3" => Direction::North,
+				_ => return Err(ParseError::at(y, line, code, "`0`, `1`, `2` or `3`")),
+			};
+
+			Ok(Instruction {
+				direction,
+				distance,
+			})
+		})
+		.collect()
+}
+
+/// The lagoon's trench outline for the given part's reading of the plan.
+pub fn render(plan: &DigPlan, part: u8) -> Polygon {
+	let instructions = match part {
+		1 => &plan.instructions,
+		_ => &plan.colour_instructions,
+	};
+
+	Polygon {
+		vertices: trench_vertices(instructions),
+		fill: Colour::rgb(70, 130, 180),
+		stroke: Colour::rgb(110, 60, 30),
+	}
+}
+
+/// The corners of the trench, starting and ending at the origin.
+pub fn trench_vertices(instructions: &[Instruction]) -> Vec<Point<i64>> {
+	let mut current = Point::new(0, 0);
+	let mut vertices = vec![current];
+
+	for instruction in instructions {
+		current = current
+			.step_by(instruction.direction, instruction.distance)
+			.expect("Trench to stay within range");
+
+		vertices.push(current);
+	}
+
+	vertices
+}
+
+/// The cubic metres dug out: the trench itself plus the lattice points it encloses, by Pick's theorem.
+pub fn compute_area(instructions: &[Instruction]) -> i64 {
+	let vertices = trench_vertices(instructions);
+
+	polygon::boundary_points(&vertices) + polygon::interior_points(&vertices)
+}
+
+/// The same area with the shoelace formula and Pick's theorem worked together by hand, as a cross-check of
+/// [`compute_area`]. Every trench edge is straight, so its length is the number of boundary points it adds.
+pub fn compute_area_by_hand(instructions: &[Instruction]) -> i64 {
+	let vertices = trench_vertices(instructions);
+	let total_distance = instructions.iter().map(|i| i.distance).sum::<i64>();
+
+	let area = vertices
+		.iter()
+		.tuple_windows()
+		.fold(0, |acc, (p1, p2)| acc + (p1.x * p2.y) - (p1.y * p2.x));
+
+	((total_distance + area) / 2) + 1
+}
+
+/// Solve both parts with [`polygon`] and by hand, or `None` if the input doesn't parse or a trench doesn't end where
+/// it started.
+pub fn compare_areas(input: &str) -> Option<((i64, i64), (i64, i64))> {
+	let plan = parse(input).ok()?;
+	let closes = |instructions: &[Instruction]| {
+		trench_vertices(instructions).last() == Some(&Point::new(0, 0))
+	};
+
+	if !closes(&plan.instructions) || !closes(&plan.colour_instructions) {
+		return None;
+	}
+
+	Some((
+		(
+			compute_area_by_hand(&plan.instructions),
+			compute_area_by_hand(&plan.colour_instructions),
+		),
+		(part_1(&plan), part_2(&plan)),
+	))
+}
+
+/// The dig plan, read both as written and as decoded from the hex colour codes.
+pub struct DigPlan {
+	pub instructions: Vec<Instruction>,
+	pub colour_instructions: Vec<Instruction>,
+}
+
+pub struct Instruction {
+	pub direction: Direction,
+	pub distance: i64,
+}
+
+#[cfg(test)]
+mod tests_day_18 {
+	use super::*;
+	use common::differential;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 62);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 62365);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 952408144115);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 159485361249806);
+	}
+
+	#[test]
+	fn area_matches_by_hand() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(
+			compare_areas(input),
+			Some(((62365, 159485361249806), (62365, 159485361249806)))
+		);
+	}
+
+	#[test]
+	fn differential_areas() {
+		if let Err(mismatch) = differential::check(generate, 10, 0..50, compare_areas) {
+			panic!("{mismatch}");
+		}
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for plan in generated(generate, 10, 0..20, parse) {
+			let vertices = trench_vertices(&plan.colour_instructions);
+
+			assert_eq!(plan.instructions.len(), 40);
+			assert_eq!(vertices.first(), vertices.last());
+			assert!(part_1(&plan) > 0);
+			assert!(part_2(&plan) > part_1(&plan));
+		}
+	}
+}
@@ -0,0 +1,649 @@
+use common::error::parse_token;
+use common::interval::{Cuboid, Interval};
+use common::parse;
+use common::rng::Rng;
+use common::solution;
+use common::{ParseError, Solution};
+use fxhash::FxHashMap;
+use serde::Deserialize;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+	type Input = (Workflows, Vec<Part>);
+	type Part1 = u64;
+	type Part2 = u64;
+	type Params = Params;
+
+	fn parse(input: &str) -> Result<(Workflows, Vec<Part>), ParseError> {
+		parse(input)
+	}
+
+	fn part_1(system: &(Workflows, Vec<Part>)) -> u64 {
+		part_1(system)
+	}
+
+	fn part_2(system: &(Workflows, Vec<Part>)) -> u64 {
+		part_2(system)
+	}
+
+	fn part_2_with(system: &(Workflows, Vec<Part>), params: &Params) -> u64 {
+		part_2_with(system, params)
+	}
+}
+
+/// The ratings each category can have in part 2.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	pub min_rating: u64,
+	pub max_rating: u64,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			min_rating: 1,
+			max_rating: 4000,
+		}
+	}
+}
+
+impl solution::Params for Params {
+	/// The range can't be empty, and must be small enough that the number of combinations of four ratings fits in a
+	/// `u64`.
+	fn validate(&self) -> Result<(), String> {
+		if self.min_rating > self.max_rating {
+			return Err(format!(
+				"`min_rating` ({}) must not be more than `max_rating` ({})",
+				self.min_rating, self.max_rating
+			));
+		}
+
+		match self.max_rating - self.min_rating < u16::MAX as u64 {
+			true => Ok(()),
+			false => Err(format!(
+				"`min_rating` to `max_rating` can span at most {} ratings",
+				u16::MAX
+			)),
+		}
+	}
+}
+
+pub fn part_1((workflows, parts): &(Workflows, Vec<Part>)) -> u64 {
+	parts
+		.iter()
+		.filter(|part| {
+			let mut workflow = workflows.get("in").expect("Initial workflow to exist");
+
+			'outer: loop {
+				for action in workflow {
+					match action {
+						Action::LessThan(code, value, action) => {
+							match get_value_from_code(part, code) < *value {
+								true => match action {
+									CompareAction::SendTo(next_workflow) => {
+										workflow = workflows
+											.get(next_workflow)
+											.expect("Next workflow to exist");
+										continue 'outer;
+									}
+									CompareAction::Accept => return true,
+									CompareAction::Reject => return false,
+								},
+								false => continue,
+							}
+						}
+						Action::GreaterThan(code, value, action) => {
+							match get_value_from_code(part, code) > *value {
+								true => match action {
+									CompareAction::SendTo(next_workflow) => {
+										workflow = workflows
+											.get(next_workflow)
+											.expect("Next workflow to exist");
+										continue 'outer;
+									}
+									CompareAction::Accept => return true,
+									CompareAction::Reject => return false,
+								},
+								false => continue,
+							}
+						}
+						Action::Accept => return true,
+						Action::Reject => return false,
+						Action::Goto(next_workflow) => {
+							workflow = workflows
+								.get(next_workflow)
+								.expect("Next workflow to exist");
+							continue 'outer;
+						}
+					}
+				}
+			}
+		})
+		.fold(0, |acc, part| acc + part.a + part.m + part.s + part.x)
+}
+
+pub fn part_2(system: &(Workflows, Vec<Part>)) -> u64 {
+	part_2_with(system, &Params::default())
+}
+
+/// How many combinations of ratings between `params.min_rating` and `params.max_rating` are accepted.
+pub fn part_2_with((workflows, _): &(Workflows, Vec<Part>), params: &Params) -> u64 {
+	let range = PartRange::new([Interval::inclusive(params.min_rating, params.max_rating); 4]);
+	let state = ComputeState {
+		workflow: "in".to_string(),
+		action_index: 0,
+	};
+
+	compute_accepted(range, state, workflows)
+}
+
+/// Part 1 by counting each part as a range of one value per category, as a cross-check of [`compute_accepted`]
+/// against the direct evaluation in [`part_1`].
+pub fn part_1_by_ranges((workflows, parts): &(Workflows, Vec<Part>)) -> u64 {
+	parts
+		.iter()
+		.filter(|part| {
+			let range = PartRange::new(["x", "m", "a", "s"].map(|code| {
+				let value = get_value_from_code(part, code);
+				Interval::inclusive(value, value)
+			}));
+			let state = ComputeState {
+				workflow: "in".to_string(),
+				action_index: 0,
+			};
+
+			compute_accepted(range, state, workflows) == 1
+		})
+		.fold(0, |acc, part| acc + part.a + part.m + part.s + part.x)
+}
+
+/// Solve part 1 by direct evaluation and by ranges, or `None` if the input doesn't parse. Workflows are assumed not to
+/// loop, as generated ones never do.
+pub fn compare_part_1(input: &str) -> Option<(u64, u64)> {
+	let system = parse(input).ok()?;
+
+	Some((part_1(&system), part_1_by_ranges(&system)))
+}
+
+/// How many parts in `range` are accepted, starting from the action at `state`.
+pub fn compute_accepted(range: PartRange, state: ComputeState, workflows: &Workflows) -> u64 {
+	let workflow = workflows.get(&state.workflow).expect("Workflow to exist");
+	let action = &workflow[state.action_index];
+
+	match action {
+		Action::LessThan(code, value, action) => {
+			let (left, right) = range.split_at(axis(code), *value);
+
+			let left_result = match action {
+				CompareAction::SendTo(next_workflow) => {
+					let state = ComputeState {
+						workflow: next_workflow.to_string(),
+						action_index: 0,
+					};
+					compute_accepted(left, state, workflows)
+				}
+				CompareAction::Accept => left.volume(),
+				CompareAction::Reject => 0,
+			};
+
+			let right_result = {
+				let state = ComputeState {
+					workflow: state.workflow,
+					action_index: state.action_index + 1,
+				};
+
+				compute_accepted(right, state, workflows)
+			};
+
+			left_result + right_result
+		}
+		Action::GreaterThan(code, value, action) => {
+			let (left, right) = range.split_at(axis(code), *value + 1);
+
+			let left_result = {
+				let state = ComputeState {
+					workflow: state.workflow,
+					action_index: state.action_index + 1,
+				};
+
+				compute_accepted(left, state, workflows)
+			};
+
+			let right_result = match action {
+				CompareAction::SendTo(next_workflow) => {
+					let state = ComputeState {
+						workflow: next_workflow.to_string(),
+						action_index: 0,
+					};
+					compute_accepted(right, state, workflows)
+				}
+				CompareAction::Accept => right.volume(),
+				CompareAction::Reject => 0,
+			};
+
+			left_result + right_result
+		}
+		Action::Accept => range.volume(),
+		Action::Reject => 0,
+		Action::Goto(code) => {
+			let state = ComputeState {
+				workflow: code.to_string(),
+				action_index: 0,
+			};
+			compute_accepted(range, state, workflows)
+		}
+	}
+}
+
+/// The ratings a range of parts can have, by category in `x`, `m`, `a`, `s` order.
+pub type PartRange = Cuboid<4>;
+
+/// The axis of a [`PartRange`] for a category.
+pub fn axis(code: &str) -> usize {
+	match code {
+		"x" => 0,
+		"m" => 1,
+		"a" => 2,
+		"s" => 3,
+		_ => unreachable!("No other codes exist"),
+	}
+}
+
+/// Where a range of parts is in the system: a workflow, and the action within it.
+pub struct ComputeState {
+	pub workflow: String,
+	pub action_index: usize,
+}
+
+/// The part's rating in a category.
+pub fn get_value_from_code(part: &Part, code: &str) -> u64 {
+	match code {
+		"a" => part.a,
+		"m" => part.m,
+		"s" => part.s,
+		"x" => part.x,
+		_ => unreachable!("No other codes exist"),
+	}
+}
+
+pub fn parse(input: &str) -> Result<(Workflows, Vec<Part>), ParseError> {
+	let blocks = parse::blocks(input);
+
+	let [workflows, parts] = blocks.as_slice() else {
+		return Err(ParseError::end_of_input(
+			input,
+			"a blank line between the workflows and parts",
+		));
+	};
+
+	let lines = workflows
+		.enumerate()
+		.map(|(y, line)| {
+			let (code, steps) =
+				parse::split_once(y, line, line, "{", "`{` after the workflow name")?;
+			let steps = steps
+				.strip_suffix('}')
+				.ok_or_else(|| ParseError::end_of_line(y, line, "`}`"))?;
+
+			Ok((y, line, code, steps.split(',').collect::<Vec<_>>()))
+		})
+		.collect::<Result<Vec<_>, ParseError>>()?;
+
+	if !lines.iter().any(|(_, _, code, _)| *code == "in") {
+		return Err(ParseError::end_of_input(input, "a workflow named `in`"));
+	}
+
+	let mut workflows = Workflows::default();
+	for (y, line, code, steps) in &lines {
+		let actions = steps
+			.iter()
+			.map(|step| parse_action(*y, line, step))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		// Every step names where parts go after its `:`, or is just the destination if it is the fallback.
+		for (step, action) in steps.iter().zip(&actions) {
+			let target = step.rsplit(':').next().unwrap_or(step);
+			let sends_on = matches!(
+				action,
+				Action::Goto(_)
+					| Action::LessThan(_, _, CompareAction::SendTo(_))
+					| Action::GreaterThan(_, _, CompareAction::SendTo(_))
+			);
+
+			if sends_on && !lines.iter().any(|(_, _, code, _)| code == &target) {
+				return Err(ParseError::at(
+					*y,
+					line,
+					target,
+					"the name of a workflow, `A` or `R`",
+				));
+			}
+		}
+
+		if !matches!(
+			actions.last(),
+			Some(Action::Accept | Action::Reject | Action::Goto(_))
+		) {
+			let last = steps[steps.len() - 1];
+			return Err(ParseError::at(
+				*y,
+				line,
+				last,
+				"a fallback workflow, `A` or `R`",
+			));
+		}
+
+		workflows.insert(code.to_string(), actions);
+	}
+
+	let parts = parts
+		.enumerate()
+		.map(|(y, line)| {
+			let mut part = Part::default();
+
+			let ratings = line
+				.strip_prefix('{')
+				.and_then(|ratings| ratings.strip_suffix('}'))
+				.ok_or_else(|| ParseError::at(y, line, line, "a part wrapped in `{}`"))?;
+
+			for (code, num) in parse::key_values(y, line, ratings, ',', '=', "a rating")? {
+				match code {
+					"a" => part.a = num,
+					"m" => part.m = num,
+					"s" => part.s = num,
+					"x" => part.x = num,
+					_ => return Err(ParseError::at(y, line, code, "`x`, `m`, `a` or `s`")),
+				}
+			}
+
+			Ok(part)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	Ok((workflows, parts))
+}
+
+/// `size` workflows and `size` parts. The workflows form a tree rooted at `in`, as in the puzzle, so every part ends up
+/// accepted or rejected.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.max(1);
+
+	let mut names = vec!["in".to_string()];
+	while names.len() < size {
+		let name = (0..rng.range(2..=3))
+			.map(|_| (b'a' + rng.index(26) as u8) as char)
+			.collect::<String>();
+
+		if !names.contains(&name) {
+			names.push(name);
+		}
+	}
+
+	// Every workflow has a few rules and a fallback, each sending parts somewhere.
+	let mut targets = names
+		.iter()
+		.map(|_| {
+			(0..=rng.range(1..=3))
+				.map(|_| *rng.choose(&["A", "R"]))
+				.map(String::from)
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+
+	// Hang each workflow off a free target of an earlier one.
+	for (i, name) in names.iter().enumerate().skip(1) {
+		loop {
+			let parent = rng.index(i);
+			let slot = rng.index(targets[parent].len());
+
+			if matches!(targets[parent][slot].as_str(), "A" | "R") {
+				targets[parent][slot] = name.clone();
+				break;
+			}
+		}
+	}
+
+	let mut workflows = names
+		.iter()
+		.zip(targets)
+		.map(|(name, targets)| {
+			// The last target is the fallback, which applies to whatever the rules before it let through.
+			let fallback = targets.len() - 1;
+			let rules = targets
+				.iter()
+				.enumerate()
+				.map(|(i, target)| match i == fallback {
+					true => target.clone(),
+					false => format!(
+						"{}{}{}:{target}",
+						rng.choose(&['x', 'm', 'a', 's']),
+						rng.choose(&['<', '>']),
+						rng.range(2..=3999)
+					),
+				})
+				.collect::<Vec<_>>()
+				.join(",");
+
+			format!("{name}{{{rules}}}")
+		})
+		.collect::<Vec<_>>();
+	rng.shuffle(&mut workflows);
+
+	let parts = (0..size)
+		.map(|_| {
+			let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=4000));
+			format!("{{x={x},m={m},a={a},s={s}}}")
+		})
+		.collect::<Vec<_>>();
+
+	format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
+
+fn parse_action(y: usize, line: &str, step: &str) -> Result<Action, ParseError> {
+	let action = match step.contains(':') {
+		true => {
+			let (code, action) = step
+				.split_once(['<', '>'])
+				.ok_or_else(|| ParseError::at(y, line, step, "a `<` or `>` comparison"))?;
+
+			if !matches!(code, "a" | "m" | "s" | "x") {
+				return Err(ParseError::at(y, line, code, "`x`, `m`, `a` or `s`"));
+			}
+
+			let (num, goto) = action
+				.split_once(':')
+				.ok_or_else(|| ParseError::at(y, line, action, "`:` after the rating"))?;
+			let num = parse_token(y, line, num, "a rating")?;
+
+			let action = match goto {
+				"A" => CompareAction::Accept,
+				"R" => CompareAction::Reject,
+				_ => CompareAction::SendTo(goto.to_string()),
+			};
+
+			match step.contains('<') {
+				true => Action::LessThan(code.to_string(), num, action),
+				false => Action::GreaterThan(code.to_string(), num, action),
+			}
+		}
+		false if step.starts_with('A') => Action::Accept,
+		false if step.starts_with('R') => Action::Reject,
+		false => Action::Goto(step.to_string()),
+	};
+
+	Ok(action)
+}
+
+pub type Workflows = FxHashMap<String, Vec<Action>>;
+
+#[derive(Debug)]
+pub enum Action {
+	LessThan(String, u64, CompareAction),
+	GreaterThan(String, u64, CompareAction),
+	Accept,
+	Reject,
+	Goto(String),
+}
+
+#[derive(Debug)]
+pub enum CompareAction {
+	SendTo(String),
+	Accept,
+	Reject,
+}
+
+#[derive(Debug, Default)]
+pub struct Part {
+	pub a: u64,
+	pub m: u64,
+	pub s: u64,
+	pub x: u64,
+}
+
+#[cfg(test)]
+mod tests_day_19 {
+	use super::*;
+	use common::differential;
+	use common::rng::generated;
+
+	#[test]
+	fn part_01_example() {
+		let input = r#"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+"#
+		.trim();
+
+		assert_eq!(part_1(&parse(input).unwrap()), 19114);
+	}
+
+	#[test]
+	fn part_01_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_1(&parse(input).unwrap()), 472630);
+	}
+
+	#[test]
+	fn part_02_example() {
+		let input = r#"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 167409079868000);
+	}
+
+	#[test]
+	fn part_02_test() {
+		let input = include_str!("part_1_input.txt");
+
+		assert_eq!(part_2(&parse(input).unwrap()), 116738260946855);
+	}
+
+	#[test]
+	fn parse_error_location() {
+		let input = r#"
+in{s<1351:A,qqz}
+qqz{s>2770:R,m<1801:A,R}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,b=2067,s=496}
+"#
+		.trim();
+
+		assert_eq!(
+			parse(input).unwrap_err().to_string(),
+			"line 5, column 14: expected `x`, `m`, `a` or `s`, found `b`"
+		);
+	}
+
+	#[test]
+	fn workflows_must_lead_somewhere() {
+		let error = |workflows: &str| {
+			let input = format!("{workflows}\n\n{{x=1,m=1,a=1,s=1}}");
+			parse(&input).unwrap_err().to_string()
+		};
+
+		assert_eq!(
+			error("ab{x<100:A,R}"),
+			"line 4, column 1: expected a workflow named `in`, found end of input"
+		);
+		assert_eq!(
+			error("in{x<100:ab,R}\nab{m>5:A,cd}"),
+			"line 2, column 10: expected the name of a workflow, `A` or `R`, found `cd`"
+		);
+		assert_eq!(
+			error("in{x<100:A,s>7:R}"),
+			"line 1, column 12: expected a fallback workflow, `A` or `R`, found `s>7:R`"
+		);
+	}
+
+	#[test]
+	fn part_02_conflicting_rules() {
+		let input = r#"
+in{x<100:ab,R}
+ab{x>200:A,A}
+
+{x=1,m=1,a=1,s=1}
+"#
+		.trim();
+
+		assert_eq!(part_2(&parse(input).unwrap()), 99 * 4000u64.pow(3));
+
+		let params = Params {
+			min_rating: 1,
+			max_rating: 10,
+		};
+		assert_eq!(part_2_with(&parse(input).unwrap(), &params), 10u64.pow(4));
+	}
+
+	#[test]
+	fn differential_part_1() {
+		if let Err(mismatch) = differential::check(generate, 20, 0..100, compare_part_1) {
+			panic!("{mismatch}");
+		}
+	}
+
+	#[test]
+	fn generated_inputs() {
+		for system in generated(generate, 30, 0..20, parse) {
+			assert_eq!(system.0.len(), 30);
+			part_1(&system);
+			assert!(part_2(&system) <= 4000u64.pow(4));
+		}
+	}
+}
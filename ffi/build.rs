@@ -0,0 +1,19 @@
+use std::path::Path;
+
+fn main() {
+	println!("cargo:rerun-if-changed=src/lib.rs");
+	println!("cargo:rerun-if-changed=cbindgen.toml");
+
+	let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("Cargo to set the manifest dir");
+	let config = cbindgen::Config::from_file(Path::new(&crate_dir).join("cbindgen.toml"))
+		.expect("cbindgen config to be valid");
+
+	let bindings = cbindgen::generate_with_config(&crate_dir, config).expect("Header to generate");
+
+	// Builds never touch the source tree. `tests/header.rs` checks the generated header against the copy in `include/`
+	// that C programs build with.
+	let header =
+		Path::new(&std::env::var("OUT_DIR").expect("Cargo to set the out dir")).join("aoc.h");
+	bindings.write_to_file(&header);
+	println!("cargo:rustc-env=AOC_GENERATED_HEADER={}", header.display());
+}
@@ -0,0 +1,68 @@
+/* Generated by cbindgen from ffi/src/lib.rs. Do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The result of a call. Anything but `AOC_STATUS_OK` means the output buffer holds an error message, if it holds
+// anything at all.
+enum AocStatus
+#if __STDC_VERSION__ >= 202311L
+  : int32_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  // The answer was written to the output buffer.
+  AOC_STATUS_OK = 0,
+  // There is no solution for the day.
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  // The part is not 1 or 2.
+  AOC_STATUS_UNKNOWN_PART = 2,
+  // The input pointer is null but its length isn't zero.
+  AOC_STATUS_NULL_INPUT = 3,
+  // The input is not valid UTF-8.
+  AOC_STATUS_INVALID_UTF8 = 4,
+  // The input could not be parsed. The output buffer holds the reason.
+  AOC_STATUS_PARSE_ERROR = 5,
+  // The output buffer can't hold the answer and its terminating NUL. `written` holds the length needed, without
+  // the NUL, so the call can be retried with a bigger buffer.
+  AOC_STATUS_BUFFER_TOO_SMALL = 6,
+  // The solver panicked.
+  AOC_STATUS_PANIC = 7,
+  // The input parsed but has no answer for the part. The output buffer holds the reason.
+  AOC_STATUS_NO_ANSWER = 8,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum AocStatus AocStatus;
+#else
+typedef int32_t AocStatus;
+#endif // __STDC_VERSION__ >= 202311L
+
+// Solve `part` of `day` for the `input_length` bytes at `input`, which must be UTF-8. Line endings, a byte order
+// mark and trailing whitespace are normalized first, as they are for the command line.
+//
+// On success the answer is written to `out` as a NUL-terminated string. Otherwise `out` holds an error message,
+// cut short if needed. Either way `written`, when not null, is set to the full length of the answer or message
+// without the NUL. Pass a null `out` with `out_length` 0 to find out how big a buffer the answer needs.
+//
+// # Safety
+//
+// `input` must be null or point to `input_length` readable bytes, and `out` must be null or point to `out_length`
+// writable bytes. `written` must be null or point to a writable `size_t`.
+AocStatus aoc_solve(uint8_t day,
+                    uint8_t part,
+                    const uint8_t *input,
+                    size_t input_length,
+                    char *out,
+                    size_t out_length,
+                    size_t *written);
+
+// A short description of a status, such as `"the input could not be parsed"`. The string is static and must not be
+// freed.
+const char *aoc_status_message(int32_t status);
+
+#endif  /* AOC_H */
@@ -0,0 +1,299 @@
+use common::error::SolveError;
+use common::Solution;
+use std::ffi::{c_char, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// The result of a call. Anything but `AOC_STATUS_OK` means the output buffer holds an error message, if it holds
+/// anything at all.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AocStatus {
+	/// The answer was written to the output buffer.
+	Ok = 0,
+	/// There is no solution for the day.
+	UnknownDay = 1,
+	/// The part is not 1 or 2.
+	UnknownPart = 2,
+	/// The input pointer is null but its length isn't zero.
+	NullInput = 3,
+	/// The input is not valid UTF-8.
+	InvalidUtf8 = 4,
+	/// The input could not be parsed. The output buffer holds the reason.
+	ParseError = 5,
+	/// The output buffer can't hold the answer and its terminating NUL. `written` holds the length needed, without
+	/// the NUL, so the call can be retried with a bigger buffer.
+	BufferTooSmall = 6,
+	/// The solver panicked.
+	Panic = 7,
+	/// The input parsed but has no answer for the part. The output buffer holds the reason.
+	NoAnswer = 8,
+}
+
+type Solver = fn(u8, &str) -> Result<String, SolveError>;
+
+/// Solve a day as the puzzle sets it, with its default parameters.
+fn solve<S: Solution>(part: u8, input: &str) -> Result<String, SolveError> {
+	common::solution::solve::<S>(part, input, &S::Params::default())
+}
+
+macro_rules! solvers {
+	($($number:literal => $krate:ident::$solution:ident $(checked by $compare:ident)?),* $(,)?) => {
+		fn find(day: u8) -> Option<Solver> {
+			let solver: Solver = match day {
+				$($number => solve::<$krate::$solution>,)*
+				_ => return None,
+			};
+
+			Some(solver)
+		}
+	};
+}
+
+common::days!(solvers);
+
+/// Solve a part of a day for an input given as bytes, returning the answer or the status and message to report.
+pub fn solve_bytes(day: u8, part: u8, input: &[u8]) -> Result<String, (AocStatus, String)> {
+	let solver = find(day).ok_or((
+		AocStatus::UnknownDay,
+		format!("no solution exists for day {day}"),
+	))?;
+
+	if part != 1 && part != 2 {
+		return Err((
+			AocStatus::UnknownPart,
+			format!("no part {part}, expected 1 or 2"),
+		));
+	}
+
+	let input = std::str::from_utf8(input).map_err(|e| {
+		(
+			AocStatus::InvalidUtf8,
+			format!("input is not valid UTF-8: {e}"),
+		)
+	})?;
+
+	match catch_unwind(AssertUnwindSafe(|| solver(part, input))) {
+		Ok(Ok(answer)) => Ok(answer),
+		Ok(Err(e @ SolveError::Parse(_))) => Err((AocStatus::ParseError, e.to_string())),
+		Ok(Err(e @ SolveError::NoAnswer(_))) => Err((AocStatus::NoAnswer, e.to_string())),
+		Err(_) => Err((
+			AocStatus::Panic,
+			"the solver panicked on this input".to_string(),
+		)),
+	}
+}
+
+/// Copy `text` into the buffer with a terminating NUL, cutting it short if it doesn't fit. Returns whether it all fit.
+///
+/// # Safety
+///
+/// `buffer` must be null or point to `length` writable bytes.
+unsafe fn write_out(text: &str, buffer: *mut c_char, length: usize, written: *mut usize) -> bool {
+	if !written.is_null() {
+		*written = text.len();
+	}
+
+	if buffer.is_null() || length == 0 {
+		return false;
+	}
+
+	let copied = text.len().min(length - 1);
+	std::ptr::copy_nonoverlapping(text.as_ptr(), buffer.cast::<u8>(), copied);
+	*buffer.add(copied) = 0;
+
+	copied == text.len()
+}
+
+/// Solve `part` of `day` for the `input_length` bytes at `input`, which must be UTF-8. Line endings, a byte order
+/// mark and trailing whitespace are normalized first, as they are for the command line.
+///
+/// On success the answer is written to `out` as a NUL-terminated string. Otherwise `out` holds an error message,
+/// cut short if needed. Either way `written`, when not null, is set to the full length of the answer or message
+/// without the NUL. Pass a null `out` with `out_length` 0 to find out how big a buffer the answer needs.
+///
+/// # Safety
+///
+/// `input` must be null or point to `input_length` readable bytes, and `out` must be null or point to `out_length`
+/// writable bytes. `written` must be null or point to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+	day: u8,
+	part: u8,
+	input: *const u8,
+	input_length: usize,
+	out: *mut c_char,
+	out_length: usize,
+	written: *mut usize,
+) -> AocStatus {
+	let input = match (input.is_null(), input_length) {
+		(true, 0) => &[],
+		(true, _) => {
+			write_out("input is null", out, out_length, written);
+			return AocStatus::NullInput;
+		}
+		(false, _) => std::slice::from_raw_parts(input, input_length),
+	};
+
+	match solve_bytes(day, part, input) {
+		Ok(answer) => match write_out(&answer, out, out_length, written) {
+			true => AocStatus::Ok,
+			false => AocStatus::BufferTooSmall,
+		},
+		Err((status, message)) => {
+			write_out(&message, out, out_length, written);
+			status
+		}
+	}
+}
+
+/// A short description of a status, such as `"the input could not be parsed"`. The string is static and must not be
+/// freed.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: i32) -> *const c_char {
+	let message: &'static CStr = match status {
+		0 => c"ok",
+		1 => c"no solution exists for the day",
+		2 => c"the part is not 1 or 2",
+		3 => c"the input is null",
+		4 => c"the input is not valid UTF-8",
+		5 => c"the input could not be parsed",
+		6 => c"the output buffer is too small",
+		7 => c"the solver panicked",
+		8 => c"the input has no answer",
+		_ => c"unknown status",
+	};
+
+	message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests_ffi {
+	use super::*;
+
+	/// Call through the C entry point with a buffer of `size` bytes.
+	fn call(day: u8, part: u8, input: &[u8], size: usize) -> (AocStatus, String, usize) {
+		let mut buffer = vec![b'x' as c_char; size];
+		let mut written = usize::MAX;
+
+		let status = unsafe {
+			aoc_solve(
+				day,
+				part,
+				input.as_ptr(),
+				input.len(),
+				buffer.as_mut_ptr(),
+				buffer.len(),
+				&mut written,
+			)
+		};
+
+		let text = match size {
+			0 => String::new(),
+			_ => unsafe { CStr::from_ptr(buffer.as_ptr()) }
+				.to_string_lossy()
+				.into_owned(),
+		};
+
+		(status, text, written)
+	}
+
+	#[test]
+	fn solves_input() {
+		let input = b"1abc2\r\npqr3stu8vwx\r\n";
+
+		assert_eq!(call(1, 1, input, 16), (AocStatus::Ok, "50".to_string(), 2));
+		assert_eq!(
+			call(1, 2, b"two1nine", 16),
+			(AocStatus::Ok, "29".to_string(), 2)
+		);
+	}
+
+	#[test]
+	fn reports_errors() {
+		assert_eq!(call(25, 1, b"", 64).0, AocStatus::UnknownDay);
+		assert_eq!(call(0, 1, b"", 64).0, AocStatus::UnknownDay);
+		assert_eq!(call(1, 3, b"", 64).0, AocStatus::UnknownPart);
+		assert_eq!(call(1, 1, &[0xff, 0xfe], 64).0, AocStatus::InvalidUtf8);
+
+		let (status, message, written) = call(5, 1, b"seeds 1", 128);
+		assert_eq!(status, AocStatus::ParseError);
+		assert_eq!(
+			message,
+			"could not parse input: line 1, column 8: expected `:` after `seeds`, found end of line"
+		);
+		assert_eq!(written, message.len());
+
+		let status = unsafe {
+			aoc_solve(
+				1,
+				1,
+				std::ptr::null(),
+				4,
+				std::ptr::null_mut(),
+				0,
+				std::ptr::null_mut(),
+			)
+		};
+		assert_eq!(status, AocStatus::NullInput);
+	}
+
+	#[test]
+	fn small_buffers_give_the_length_needed() {
+		let input = b"1abc2\npqr3stu8vwx";
+
+		assert_eq!(
+			call(1, 1, input, 2),
+			(AocStatus::BufferTooSmall, "5".to_string(), 2)
+		);
+		assert_eq!(
+			call(1, 1, input, 0),
+			(AocStatus::BufferTooSmall, String::new(), 2)
+		);
+		assert_eq!(call(1, 1, input, 3), (AocStatus::Ok, "50".to_string(), 2));
+
+		let mut written = 0;
+		let status = unsafe {
+			aoc_solve(
+				1,
+				1,
+				input.as_ptr(),
+				input.len(),
+				std::ptr::null_mut(),
+				0,
+				&mut written,
+			)
+		};
+		assert_eq!((status, written), (AocStatus::BufferTooSmall, 2));
+	}
+
+	#[test]
+	fn inputs_without_an_answer() {
+		let (status, message, _) = call(8, 1, b"L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)", 128);
+
+		assert_eq!(status, AocStatus::NoAnswer);
+		assert_eq!(
+			message,
+			"the input has no answer: there is no node `AAA` to start from"
+		);
+	}
+
+	#[test]
+	fn panics_stay_on_this_side() {
+		// A line without any digits parses, but the solver expects every line to have one.
+		let (status, message, _) = call(1, 1, b"1abc2\nno digits here", 64);
+
+		assert_eq!(status, AocStatus::Panic);
+		assert_eq!(message, "the solver panicked on this input");
+	}
+
+	#[test]
+	fn status_messages() {
+		let message = |status| unsafe { CStr::from_ptr(aoc_status_message(status)) };
+
+		assert_eq!(
+			message(AocStatus::ParseError as i32),
+			c"the input could not be parsed"
+		);
+		assert_eq!(message(42), c"unknown status");
+	}
+}
@@ -0,0 +1,83 @@
+/* Exercises the C entry points in `aoc.h`. Exits with 0 when every check passes. */
+
+#include "aoc.h"
+
+#include <stdio.h>
+#include <string.h>
+
+static int failures = 0;
+
+static void check(int passed, const char *what) {
+	if (!passed) {
+		fprintf(stderr, "FAIL: %s\n", what);
+		failures++;
+	}
+}
+
+static AocStatus solve(uint8_t day, uint8_t part, const char *input, char *out, size_t out_length,
+		       size_t *written) {
+	return aoc_solve(day, part, (const uint8_t *)input, strlen(input), out, out_length, written);
+}
+
+int main(void) {
+	char out[256];
+	size_t written = 0;
+
+	AocStatus status = solve(1, 1, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", out, sizeof out, &written);
+	check(status == AOC_STATUS_OK, "day 1 part 1 solves");
+	check(strcmp(out, "142") == 0, "day 1 part 1 answer is 142");
+	check(written == 3, "written is the answer's length");
+
+	status = solve(1, 2, "two1nine\r\neightwothree\r\n", out, sizeof out, &written);
+	check(status == AOC_STATUS_OK, "CRLF input solves");
+	check(strcmp(out, "112") == 0, "day 1 part 2 answer is 112");
+
+	status = solve(5, 1, "seeds 79 14", out, sizeof out, &written);
+	check(status == AOC_STATUS_PARSE_ERROR, "bad input is a parse error");
+	check(strstr(out, "expected `:` after `seeds`") != NULL, "parse error explains itself");
+	check(written == strlen(out), "written is the message's length");
+
+	status = solve(25, 1, "", out, sizeof out, NULL);
+	check(status == AOC_STATUS_UNKNOWN_DAY, "day 25 is unknown");
+
+	status = solve(1, 3, "", out, sizeof out, NULL);
+	check(status == AOC_STATUS_UNKNOWN_PART, "part 3 is unknown");
+
+	status = aoc_solve(1, 1, NULL, 4, out, sizeof out, NULL);
+	check(status == AOC_STATUS_NULL_INPUT, "null input with a length is refused");
+
+	const uint8_t invalid[] = {0xff, 0xfe};
+	status = aoc_solve(1, 1, invalid, sizeof invalid, out, sizeof out, NULL);
+	check(status == AOC_STATUS_INVALID_UTF8, "invalid UTF-8 is refused");
+
+	status = solve(17, 1, "1", out, sizeof out, NULL);
+	check(status == AOC_STATUS_NO_ANSWER, "an input without an answer comes back as a status");
+
+	status = solve(1, 1, "1abc2\nno digits here", out, sizeof out, NULL);
+	check(status == AOC_STATUS_PANIC, "a panic comes back as a status");
+
+	/* Ask for the length first, then solve into a buffer of exactly the right size. */
+	const char *input = "1abc2\npqr3stu8vwx";
+	status = solve(1, 1, input, NULL, 0, &written);
+	check(status == AOC_STATUS_BUFFER_TOO_SMALL, "a null buffer is too small");
+	check(written == 2, "the length needed is reported");
+
+	char exact[3];
+	status = solve(1, 1, input, exact, written + 1, NULL);
+	check(status == AOC_STATUS_OK, "a buffer of the reported length is big enough");
+	check(strcmp(exact, "50") == 0, "the retried answer is 50");
+
+	status = solve(1, 1, input, exact, 2, NULL);
+	check(status == AOC_STATUS_BUFFER_TOO_SMALL, "no room for the NUL is too small");
+	check(strcmp(exact, "5") == 0, "a short buffer holds the start of the answer");
+
+	check(strcmp(aoc_status_message(AOC_STATUS_PARSE_ERROR), "the input could not be parsed") == 0,
+	      "status messages describe the status");
+	check(strcmp(aoc_status_message(99), "unknown status") == 0, "unknown statuses have a message");
+
+	if (failures == 0) {
+		printf("All checks passed\n");
+	}
+
+	return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The directory holding this test binary, which is where Cargo puts the shared library it was built against.
+fn library_dir() -> PathBuf {
+	std::env::current_exe()
+		.expect("Test binary to have a path")
+		.parent()
+		.expect("Test binary to be in a directory")
+		.to_path_buf()
+}
+
+#[test]
+fn c_program_passes() {
+	let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("test_aoc");
+	let compiler = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+	let compiled = Command::new(&compiler)
+		.arg(manifest_dir.join("tests/c/test_aoc.c"))
+		.arg("-std=c99")
+		.arg("-Wall")
+		.arg("-Werror")
+		.arg("-I")
+		.arg(manifest_dir.join("include"))
+		.arg("-L")
+		.arg(library_dir())
+		.arg("-laoc_ffi")
+		.arg("-o")
+		.arg(&program)
+		.status()
+		.unwrap_or_else(|e| panic!("`{compiler}` to run, or `CC` to name a C compiler: {e}"));
+
+	assert!(compiled.success(), "C program to compile");
+
+	let output = Command::new(&program)
+		.env("LD_LIBRARY_PATH", library_dir())
+		.env("DYLD_LIBRARY_PATH", library_dir())
+		.output()
+		.expect("C program to run");
+
+	assert!(
+		output.status.success(),
+		"C program to pass:\n{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert_eq!(
+		String::from_utf8_lossy(&output.stdout),
+		"All checks passed\n"
+	);
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+/// `include/aoc.h` is checked in for C programs to build with, so it has to match the header cbindgen generates from
+/// the crate. Run this test with `AOC_UPDATE_HEADER=1` set to regenerate it after changing the C API.
+#[test]
+fn checked_in_header_is_current() {
+	let generated = std::fs::read_to_string(env!("AOC_GENERATED_HEADER"))
+		.expect("Build script to generate the header");
+	let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+
+	if std::env::var_os("AOC_UPDATE_HEADER").is_some() {
+		std::fs::write(&path, &generated).expect("Header to be writable");
+	}
+
+	let checked_in = std::fs::read_to_string(&path).expect("Header to be checked in");
+
+	assert!(
+		checked_in == generated,
+		"`ffi/include/aoc.h` is out of date, regenerate it with `AOC_UPDATE_HEADER=1 cargo test -p aoc_ffi --test header`"
+	);
+}